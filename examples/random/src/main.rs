@@ -3,7 +3,7 @@ use rand::prelude::*;
 
 fn main() {
     let mut rng = rand::thread_rng();
-    let board = Array2D::filled_by_row_major(|| rng.gen_range(0, 10), 3, 2);
+    let board = Array2D::filled_by_row_major(3, 2, || rng.gen_range(0, 10));
     println!("{:?}", board);
 
     let mut counter = 1;
@@ -12,6 +12,6 @@ fn main() {
         counter += 1;
         tmp
     };
-    let board2 = Array2D::filled_by_column_major(f, 2, 3);
+    let board2 = Array2D::filled_by_column_major(2, 3, f);
     println!("{:?}", board2);
 }
@@ -0,0 +1,368 @@
+//! Summaries of each row or column of an [`Array2D`].
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{Array2D, ColumnIter, Layout, RowIter, Storage};
+use std::marker::PhantomData;
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Folds the elements of each row, from left to right, into a single
+    /// value. Every row starts from a clone of `init`. Returns one value per
+    /// row. See [`fold_rows_as_column`] for a version that returns an
+    /// [`Array2D`] with a single column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(array.fold_rows(0, |total, element| total + element), vec![6, 15]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`fold_rows_as_column`]: struct.Array2D.html#method.fold_rows_as_column
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn fold_rows<B, F>(&self, init: B, mut folder: F) -> Vec<B>
+    where
+        B: Clone,
+        F: FnMut(B, &T) -> B,
+    {
+        self.rows_iter()
+            .map(|row| row.fold(init.clone(), &mut folder))
+            .collect()
+    }
+
+    /// Folds the elements of each column, from top to bottom, into a single
+    /// value. Every column starts from a clone of `init`. Returns one value
+    /// per column. See [`fold_columns_as_row`] for a version that returns an
+    /// [`Array2D`] with a single row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(array.fold_columns(0, |total, element| total + element), vec![5, 7, 9]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`fold_columns_as_row`]: struct.Array2D.html#method.fold_columns_as_row
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn fold_columns<B, F>(&self, init: B, mut folder: F) -> Vec<B>
+    where
+        B: Clone,
+        F: FnMut(B, &T) -> B,
+    {
+        self.columns_iter()
+            .map(|column| column.fold(init.clone(), &mut folder))
+            .collect()
+    }
+
+    /// Reduces the elements of each row, from left to right, to a single
+    /// element by starting from a clone of the first element and repeatedly
+    /// calling `reducer`. Returns one value per row, which is [`None`] if the
+    /// rows are empty. See [`reduce_rows_as_column`] for a version that
+    /// returns an [`Array2D`] with a single column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 8, 3], vec![7, 5, 6]])?;
+    /// assert_eq!(array.reduce_rows(|max, &element| max.max(element)), vec![Some(8), Some(7)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`reduce_rows_as_column`]: struct.Array2D.html#method.reduce_rows_as_column
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn reduce_rows<F>(&self, mut reducer: F) -> Vec<Option<T>>
+    where
+        T: Clone,
+        F: FnMut(T, &T) -> T,
+    {
+        self.rows_iter()
+            .map(|row| reduce(row, &mut reducer))
+            .collect()
+    }
+
+    /// Reduces the elements of each column, from top to bottom, to a single
+    /// element by starting from a clone of the first element and repeatedly
+    /// calling `reducer`. Returns one value per column, which is [`None`] if
+    /// the columns are empty. See [`reduce_columns_as_row`] for a version
+    /// that returns an [`Array2D`] with a single row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 8, 3], vec![7, 5, 6]])?;
+    /// assert_eq!(
+    ///     array.reduce_columns(|max, &element| max.max(element)),
+    ///     vec![Some(7), Some(8), Some(6)]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`reduce_columns_as_row`]: struct.Array2D.html#method.reduce_columns_as_row
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn reduce_columns<F>(&self, mut reducer: F) -> Vec<Option<T>>
+    where
+        T: Clone,
+        F: FnMut(T, &T) -> T,
+    {
+        self.columns_iter()
+            .map(|column| reduce(column, &mut reducer))
+            .collect()
+    }
+
+    /// Calls `mapper` on each row and returns one value per row. Each row is
+    /// passed as a [`RowIter`] over its elements rather than as a slice,
+    /// because the rows of an array with a column major [`Layout`] are not
+    /// contiguous in memory. See [`map_rows_as_column`] for a version that
+    /// returns an [`Array2D`] with a single column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![3, 1, 2], vec![4, 6, 5]])?;
+    /// assert_eq!(array.map_rows(|row| row.max().copied()), vec![Some(3), Some(6)]);
+    /// assert_eq!(array.map_rows(|row| row.cloned().collect::<Vec<_>>()), array.as_rows());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RowIter`]: struct.RowIter.html
+    /// [`Layout`]: enum.Layout.html
+    /// [`map_rows_as_column`]: struct.Array2D.html#method.map_rows_as_column
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn map_rows<F, U>(&self, mapper: F) -> Vec<U>
+    where
+        F: FnMut(RowIter<'_, T>) -> U,
+    {
+        self.rows_iter().map(mapper).collect()
+    }
+
+    /// Calls `mapper` on each column and returns one value per column. Each
+    /// column is passed as a [`ColumnIter`] over its elements rather than as
+    /// a slice, because the columns of an array with the default row major
+    /// [`Layout`] are not contiguous in memory. See [`map_columns_as_row`]
+    /// for a version that returns an [`Array2D`] with a single row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![3, 1, 2], vec![4, 6, 5]])?;
+    /// assert_eq!(array.map_columns(|column| column.count()), vec![2, 2, 2]);
+    /// assert_eq!(array.map_columns(|column| column.max().copied()), vec![Some(4), Some(6), Some(5)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ColumnIter`]: struct.ColumnIter.html
+    /// [`Layout`]: enum.Layout.html
+    /// [`map_columns_as_row`]: struct.Array2D.html#method.map_columns_as_row
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn map_columns<F, U>(&self, mapper: F) -> Vec<U>
+    where
+        F: FnMut(ColumnIter<'_, T>) -> U,
+    {
+        self.columns_iter().map(mapper).collect()
+    }
+
+    /// Like [`fold_rows`], but returns an [`Array2D`] with the same number of
+    /// rows and a single column, so that each result stays in its row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let totals = array.fold_rows_as_column(0, |total, element| total + element);
+    /// assert_eq!(totals.as_rows(), vec![vec![6], vec![15]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`fold_rows`]: struct.Array2D.html#method.fold_rows
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn fold_rows_as_column<B, F>(&self, init: B, folder: F) -> Array2D<B>
+    where
+        B: Clone,
+        F: FnMut(B, &T) -> B,
+    {
+        self.per_row(self.fold_rows(init, folder))
+    }
+
+    /// Like [`fold_columns`], but returns an [`Array2D`] with the same number
+    /// of columns and a single row, so that each result stays in its column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let totals = array.fold_columns_as_row(0, |total, element| total + element);
+    /// assert_eq!(totals.as_rows(), vec![vec![5, 7, 9]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`fold_columns`]: struct.Array2D.html#method.fold_columns
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn fold_columns_as_row<B, F>(&self, init: B, folder: F) -> Array2D<B>
+    where
+        B: Clone,
+        F: FnMut(B, &T) -> B,
+    {
+        self.per_column(self.fold_columns(init, folder))
+    }
+
+    /// Like [`reduce_rows`], but returns an [`Array2D`] with the same number
+    /// of rows and a single column, so that each result stays in its row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 8, 3], vec![7, 5, 6]])?;
+    /// let maxima = array.reduce_rows_as_column(|max, &element| max.max(element));
+    /// assert_eq!(maxima.as_rows(), vec![vec![Some(8)], vec![Some(7)]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`reduce_rows`]: struct.Array2D.html#method.reduce_rows
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn reduce_rows_as_column<F>(&self, reducer: F) -> Array2D<Option<T>>
+    where
+        T: Clone,
+        F: FnMut(T, &T) -> T,
+    {
+        self.per_row(self.reduce_rows(reducer))
+    }
+
+    /// Like [`reduce_columns`], but returns an [`Array2D`] with the same
+    /// number of columns and a single row, so that each result stays in its
+    /// column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 8, 3], vec![7, 5, 6]])?;
+    /// let maxima = array.reduce_columns_as_row(|max, &element| max.max(element));
+    /// assert_eq!(maxima.as_rows(), vec![vec![Some(7), Some(8), Some(6)]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`reduce_columns`]: struct.Array2D.html#method.reduce_columns
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn reduce_columns_as_row<F>(&self, reducer: F) -> Array2D<Option<T>>
+    where
+        T: Clone,
+        F: FnMut(T, &T) -> T,
+    {
+        self.per_column(self.reduce_columns(reducer))
+    }
+
+    /// Like [`map_rows`], but returns an [`Array2D`] with the same number of
+    /// rows and a single column, so that each result stays in its row. Each
+    /// row is passed to `mapper` as a [`RowIter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![3, 1, 2], vec![4, 6, 5]])?;
+    /// let lengths = array.map_rows_as_column(|row| row.len());
+    /// assert_eq!(lengths.as_rows(), vec![vec![3], vec![3]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`map_rows`]: struct.Array2D.html#method.map_rows
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`RowIter`]: struct.RowIter.html
+    pub fn map_rows_as_column<F, U>(&self, mapper: F) -> Array2D<U>
+    where
+        F: FnMut(RowIter<'_, T>) -> U,
+    {
+        self.per_row(self.map_rows(mapper))
+    }
+
+    /// Like [`map_columns`], but returns an [`Array2D`] with the same number
+    /// of columns and a single row, so that each result stays in its column.
+    /// Each column is passed to `mapper` as a [`ColumnIter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![3, 1, 2], vec![4, 6, 5]])?;
+    /// let totals = array.map_columns_as_row(|column| column.sum::<i32>());
+    /// assert_eq!(totals.as_rows(), vec![vec![7, 7, 7]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`map_columns`]: struct.Array2D.html#method.map_columns
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`ColumnIter`]: struct.ColumnIter.html
+    pub fn map_columns_as_row<F, U>(&self, mapper: F) -> Array2D<U>
+    where
+        F: FnMut(ColumnIter<'_, T>) -> U,
+    {
+        self.per_column(self.map_columns(mapper))
+    }
+
+    fn per_row<U>(&self, array: Vec<U>) -> Array2D<U> {
+        Array2D {
+            array,
+            num_rows: self.num_rows,
+            num_columns: 1,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
+        }
+    }
+
+    fn per_column<U>(&self, array: Vec<U>) -> Array2D<U> {
+        Array2D {
+            array,
+            num_rows: 1,
+            num_columns: self.num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
+        }
+    }
+}
+
+fn reduce<'a, T, I, F>(mut elements: I, reducer: F) -> Option<T>
+where
+    T: Clone + 'a,
+    I: Iterator<Item = &'a T>,
+    F: FnMut(T, &T) -> T,
+{
+    let first = elements.next()?.clone();
+    Some(elements.fold(first, reducer))
+}
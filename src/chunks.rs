@@ -0,0 +1,470 @@
+//! Iterators over non-overlapping rectangular blocks of an [`Array2D`].
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{Array2D, ArrayView, ArrayViewMut, Storage, StorageMut};
+use std::iter::FusedIterator;
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns an [`Iterator`] over non-overlapping blocks of the array, each
+    /// `chunk_rows` tall and `chunk_columns` wide. Each [`Item`] is a tuple of
+    /// the block coordinates, given as `(block_row, block_column)`, and an
+    /// [`ArrayView`] of the block. The blocks are produced in [row major
+    /// order].
+    ///
+    /// If the dimensions of the array are not multiples of the chunk size, the
+    /// blocks along the bottom and right edges are smaller. See
+    /// [`chunks_exact`] for a version that skips those blocks instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_rows` or `chunk_columns` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_iter_row_major(3, 3, 1..)?;
+    /// let mut chunks = array.chunks(2, 2);
+    ///
+    /// let (block, chunk) = chunks.next().unwrap();
+    /// assert_eq!(block, (0, 0));
+    /// assert_eq!(chunk.to_owned().as_rows(), vec![vec![1, 2], vec![4, 5]]);
+    ///
+    /// let (block, chunk) = chunks.next().unwrap();
+    /// assert_eq!(block, (0, 1));
+    /// assert_eq!(chunk.to_owned().as_rows(), vec![vec![3], vec![6]]);
+    ///
+    /// let (block, chunk) = chunks.next().unwrap();
+    /// assert_eq!(block, (1, 0));
+    /// assert_eq!(chunk.to_owned().as_rows(), vec![vec![7, 8]]);
+    ///
+    /// let (block, chunk) = chunks.next().unwrap();
+    /// assert_eq!(block, (1, 1));
+    /// assert_eq!(chunk.to_owned().as_rows(), vec![vec![9]]);
+    ///
+    /// assert!(chunks.next().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`ArrayView`]: struct.ArrayView.html
+    /// [`chunks_exact`]: struct.Array2D.html#method.chunks_exact
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn chunks(&self, chunk_rows: usize, chunk_columns: usize) -> Chunks<'_, T> {
+        Chunks {
+            grid: BlockGrid::new(
+                self.num_rows,
+                self.num_columns,
+                chunk_rows,
+                chunk_columns,
+                false,
+            ),
+            view: self.view(),
+        }
+    }
+
+    /// Returns an [`Iterator`] over non-overlapping blocks of the array, each
+    /// exactly `chunk_rows` tall and `chunk_columns` wide. Each [`Item`] is a
+    /// tuple of the block coordinates, given as `(block_row, block_column)`,
+    /// and an [`ArrayView`] of the block. The blocks are produced in [row major
+    /// order].
+    ///
+    /// If the dimensions of the array are not multiples of the chunk size, the
+    /// elements along the bottom and right edges that do not fit in a full
+    /// block are skipped. They can be retrieved with [`ChunksExact::remainder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_rows` or `chunk_columns` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_iter_row_major(3, 5, 1..)?;
+    /// let chunks = array.chunks_exact(2, 2);
+    ///
+    /// let (right, bottom) = chunks.remainder();
+    /// assert_eq!(right.to_owned().as_rows(), vec![vec![5], vec![10]]);
+    /// assert_eq!(bottom.to_owned().as_rows(), vec![vec![11, 12, 13, 14, 15]]);
+    ///
+    /// let sums = chunks
+    ///     .map(|(_, chunk)| chunk.elements_row_major_iter().sum::<i32>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sums, vec![1 + 2 + 6 + 7, 3 + 4 + 8 + 9]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`ArrayView`]: struct.ArrayView.html
+    /// [`ChunksExact::remainder`]: struct.ChunksExact.html#method.remainder
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn chunks_exact(&self, chunk_rows: usize, chunk_columns: usize) -> ChunksExact<'_, T> {
+        ChunksExact {
+            grid: BlockGrid::new(
+                self.num_rows,
+                self.num_columns,
+                chunk_rows,
+                chunk_columns,
+                true,
+            ),
+            view: self.view(),
+        }
+    }
+
+    /// Returns an [`Iterator`] over non-overlapping mutable blocks of the
+    /// array. This is the mutable version of [`chunks`]: each [`Item`] is a
+    /// tuple of the block coordinates and an [`ArrayViewMut`] of the block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_rows` or `chunk_columns` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(3, 3, 0);
+    /// for ((block_row, block_column), mut chunk) in array.chunks_mut(2, 2) {
+    ///     for element in chunk.elements_row_major_iter_mut() {
+    ///         *element = block_row * 10 + block_column;
+    ///     }
+    /// }
+    /// assert_eq!(
+    ///     array.as_rows(),
+    ///     vec![vec![0, 0, 1], vec![0, 0, 1], vec![10, 10, 11]]
+    /// );
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`ArrayViewMut`]: struct.ArrayViewMut.html
+    /// [`chunks`]: struct.Array2D.html#method.chunks
+    pub fn chunks_mut(&mut self, chunk_rows: usize, chunk_columns: usize) -> ChunksMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        ChunksMut {
+            grid: BlockGrid::new(
+                self.num_rows,
+                self.num_columns,
+                chunk_rows,
+                chunk_columns,
+                false,
+            ),
+            view: self.view_mut(),
+        }
+    }
+
+    /// Returns an [`Iterator`] over non-overlapping mutable blocks of the
+    /// array, each exactly `chunk_rows` tall and `chunk_columns` wide. This is
+    /// the mutable version of [`chunks_exact`]: each [`Item`] is a tuple of the
+    /// block coordinates and an [`ArrayViewMut`] of the block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_rows` or `chunk_columns` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(3, 3, 0);
+    /// let mut chunks = array.chunks_exact_mut(2, 2);
+    /// for (_, mut chunk) in &mut chunks {
+    ///     chunk[(0, 0)] = 1;
+    /// }
+    /// let (mut right, mut bottom) = chunks.into_remainder();
+    /// right[(0, 0)] = 2;
+    /// bottom[(0, 2)] = 3;
+    /// assert_eq!(
+    ///     array.as_rows(),
+    ///     vec![vec![1, 0, 2], vec![0, 0, 0], vec![0, 0, 3]]
+    /// );
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`ArrayViewMut`]: struct.ArrayViewMut.html
+    /// [`chunks_exact`]: struct.Array2D.html#method.chunks_exact
+    pub fn chunks_exact_mut(
+        &mut self,
+        chunk_rows: usize,
+        chunk_columns: usize,
+    ) -> ChunksExactMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        ChunksExactMut {
+            grid: BlockGrid::new(
+                self.num_rows,
+                self.num_columns,
+                chunk_rows,
+                chunk_columns,
+                true,
+            ),
+            view: self.view_mut(),
+        }
+    }
+}
+
+/// An [`Iterator`] over non-overlapping blocks of an [`Array2D`], created by
+/// [`Array2D::chunks`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::chunks`]: struct.Array2D.html#method.chunks
+#[derive(Debug)]
+pub struct Chunks<'a, T> {
+    view: ArrayView<'a, T>,
+    grid: BlockGrid,
+}
+
+/// An [`Iterator`] over non-overlapping blocks of an [`Array2D`] that all have
+/// the same size, created by [`Array2D::chunks_exact`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::chunks_exact`]: struct.Array2D.html#method.chunks_exact
+#[derive(Debug)]
+pub struct ChunksExact<'a, T> {
+    view: ArrayView<'a, T>,
+    grid: BlockGrid,
+}
+
+/// An [`Iterator`] over non-overlapping mutable blocks of an [`Array2D`],
+/// created by [`Array2D::chunks_mut`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::chunks_mut`]: struct.Array2D.html#method.chunks_mut
+#[derive(Debug)]
+pub struct ChunksMut<'a, T> {
+    view: ArrayViewMut<'a, T>,
+    grid: BlockGrid,
+}
+
+/// An [`Iterator`] over non-overlapping mutable blocks of an [`Array2D`] that
+/// all have the same size, created by [`Array2D::chunks_exact_mut`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::chunks_exact_mut`]: struct.Array2D.html#method.chunks_exact_mut
+#[derive(Debug)]
+pub struct ChunksExactMut<'a, T> {
+    view: ArrayViewMut<'a, T>,
+    grid: BlockGrid,
+}
+
+impl<'a, T> ChunksExact<'a, T> {
+    /// Returns the elements that are not part of any full block, as a tuple
+    /// of two non-overlapping views `(right, bottom)`. `right` contains the
+    /// leftover columns next to the full blocks, and `bottom` contains the
+    /// leftover rows across the whole width of the array.
+    pub fn remainder(&self) -> (ArrayView<'a, T>, ArrayView<'a, T>) {
+        let (right, bottom) = self.grid.remainder();
+        (self.chunk(right), self.chunk(bottom))
+    }
+}
+
+impl<'a, T> ChunksExactMut<'a, T> {
+    /// Returns the elements that are not part of any full block, as a tuple
+    /// of two non-overlapping mutable views `(right, bottom)`. See
+    /// [`ChunksExact::remainder`].
+    ///
+    /// [`ChunksExact::remainder`]: struct.ChunksExact.html#method.remainder
+    pub fn into_remainder(self) -> (ArrayViewMut<'a, T>, ArrayViewMut<'a, T>) {
+        // The two regions are disjoint from each other and from every full
+        // block, and consuming `self` ends the iteration.
+        let (right, bottom) = self.grid.remainder();
+        (self.chunk(right), self.chunk(bottom))
+    }
+}
+
+impl<'a, T> Chunks<'a, T> {
+    fn chunk(&self, (row, column, num_rows, num_columns): Region) -> ArrayView<'a, T> {
+        self.view.subview(row, column, num_rows, num_columns)
+    }
+}
+
+impl<'a, T> ChunksExact<'a, T> {
+    fn chunk(&self, (row, column, num_rows, num_columns): Region) -> ArrayView<'a, T> {
+        self.view.subview(row, column, num_rows, num_columns)
+    }
+}
+
+impl<'a, T> ChunksMut<'a, T> {
+    fn chunk(&self, (row, column, num_rows, num_columns): Region) -> ArrayViewMut<'a, T> {
+        // SAFETY: Each block is produced at most once and the blocks never
+        // overlap.
+        unsafe {
+            self.view
+                .subview_unchecked(row, column, num_rows, num_columns)
+        }
+    }
+}
+
+impl<'a, T> ChunksExactMut<'a, T> {
+    fn chunk(&self, (row, column, num_rows, num_columns): Region) -> ArrayViewMut<'a, T> {
+        // SAFETY: Each block is produced at most once and the blocks never
+        // overlap.
+        unsafe {
+            self.view
+                .subview_unchecked(row, column, num_rows, num_columns)
+        }
+    }
+}
+
+impl<T> Clone for Chunks<'_, T> {
+    fn clone(&self) -> Self {
+        Chunks {
+            view: self.view,
+            grid: self.grid.clone(),
+        }
+    }
+}
+
+impl<T> Clone for ChunksExact<'_, T> {
+    fn clone(&self) -> Self {
+        ChunksExact {
+            view: self.view,
+            grid: self.grid.clone(),
+        }
+    }
+}
+
+macro_rules! impl_chunks_iterator {
+    ($name:ident, $view:ident) => {
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = ((usize, usize), $view<'a, T>);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let block = self.grid.next()?;
+                Some((block, self.chunk(self.grid.region(block))))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.grid.len();
+                (len, Some(len))
+            }
+        }
+
+        impl<T> DoubleEndedIterator for $name<'_, T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let block = self.grid.next_back()?;
+                Some((block, self.chunk(self.grid.region(block))))
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+impl_chunks_iterator!(Chunks, ArrayView);
+impl_chunks_iterator!(ChunksExact, ArrayView);
+impl_chunks_iterator!(ChunksMut, ArrayViewMut);
+impl_chunks_iterator!(ChunksExactMut, ArrayViewMut);
+
+/// The bookkeeping shared by all of the chunk iterators. Blocks are numbered
+/// in row major order and `front..back` are the ones not yet produced.
+#[derive(Debug, Clone)]
+struct BlockGrid {
+    num_rows: usize,
+    num_columns: usize,
+    chunk_rows: usize,
+    chunk_columns: usize,
+    block_columns: usize,
+    front: usize,
+    back: usize,
+}
+
+/// A rectangular region, given as `(row, column, num_rows, num_columns)`.
+type Region = (usize, usize, usize, usize);
+
+impl BlockGrid {
+    fn new(
+        num_rows: usize,
+        num_columns: usize,
+        chunk_rows: usize,
+        chunk_columns: usize,
+        exact: bool,
+    ) -> Self {
+        assert!(
+            chunk_rows != 0 && chunk_columns != 0,
+            "chunk size must be non-zero"
+        );
+        let (block_rows, block_columns) = if exact {
+            (num_rows / chunk_rows, num_columns / chunk_columns)
+        } else {
+            (
+                num_rows.div_ceil(chunk_rows),
+                num_columns.div_ceil(chunk_columns),
+            )
+        };
+        BlockGrid {
+            num_rows,
+            num_columns,
+            chunk_rows,
+            chunk_columns,
+            block_columns,
+            front: 0,
+            back: block_rows * block_columns,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.front == self.back {
+            return None;
+        }
+        let index = self.front;
+        self.front += 1;
+        Some(self.block(index))
+    }
+
+    fn next_back(&mut self) -> Option<(usize, usize)> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.block(self.back))
+    }
+
+    fn block(&self, index: usize) -> (usize, usize) {
+        (index / self.block_columns, index % self.block_columns)
+    }
+
+    /// The region covered by the given block, clipped to the array.
+    fn region(&self, (block_row, block_column): (usize, usize)) -> Region {
+        let row = block_row * self.chunk_rows;
+        let column = block_column * self.chunk_columns;
+        (
+            row,
+            column,
+            self.chunk_rows.min(self.num_rows - row),
+            self.chunk_columns.min(self.num_columns - column),
+        )
+    }
+
+    /// The regions to the right of and below all the full blocks.
+    fn remainder(&self) -> (Region, Region) {
+        let full_rows = self.num_rows - self.num_rows % self.chunk_rows;
+        let full_columns = self.num_columns - self.num_columns % self.chunk_columns;
+        (
+            (0, full_columns, full_rows, self.num_columns - full_columns),
+            (full_rows, 0, self.num_rows - full_rows, self.num_columns),
+        )
+    }
+}
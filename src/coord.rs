@@ -0,0 +1,278 @@
+//! Named types for the indices of the elements of an [`Array2D`] and the
+//! directions between them.
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use std::ops::{Add, Sub};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The indices of an element of an [`Array2D`], as a row and a column.
+///
+/// A [`Coord`] can be used wherever indices are given as a `(row, column)`
+/// tuple, either by indexing an [`Array2D`] with it directly or by converting
+/// it with [`From`] and [`Into`]. It can be moved by a signed `(row, column)`
+/// offset or by a [`Direction`] with `+` and `-`, which panic if the result
+/// would be negative, or with [`checked_add`], which returns [`None`]
+/// instead.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::{Array2D, Coord, Direction};
+/// let mut array = Array2D::filled_with(3, 3, 0);
+/// let center = Coord::new(1, 1);
+/// array[center + Direction::North] = 1;
+/// array[center + (1, 1)] = 2;
+/// assert_eq!(array.as_rows(), vec![vec![0, 1, 0], vec![0, 0, 0], vec![0, 0, 2]]);
+///
+/// let (row, column) = center.into();
+/// assert_eq!(array[(row, column)], array[center]);
+/// assert_eq!(center.checked_add((-2, 0)), None);
+/// ```
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [`Coord`]: struct.Coord.html
+/// [`Direction`]: enum.Direction.html
+/// [`checked_add`]: struct.Coord.html#method.checked_add
+/// [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
+/// [`Into`]: https://doc.rust-lang.org/std/convert/trait.Into.html
+/// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct Coord {
+    /// The index of the row.
+    pub row: usize,
+    /// The index of the column.
+    pub column: usize,
+}
+
+impl Coord {
+    /// Creates a new [`Coord`] from the indices of a row and a column.
+    ///
+    /// [`Coord`]: struct.Coord.html
+    pub const fn new(row: usize, column: usize) -> Self {
+        Coord { row, column }
+    }
+
+    /// Moves by a signed `(row, column)` offset, returning [`None`] if either
+    /// index would be negative or would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::Coord;
+    /// let coord = Coord::new(2, 0);
+    /// assert_eq!(coord.checked_add((-1, 3)), Some(Coord::new(1, 3)));
+    /// assert_eq!(coord.checked_add((0, -1)), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn checked_add(self, (row_offset, column_offset): (isize, isize)) -> Option<Self> {
+        Some(Coord {
+            row: self.row.checked_add_signed(row_offset)?,
+            column: self.column.checked_add_signed(column_offset)?,
+        })
+    }
+
+    fn checked_sub(self, (row_offset, column_offset): (isize, isize)) -> Option<Self> {
+        Some(Coord {
+            row: checked_sub_signed(self.row, row_offset)?,
+            column: checked_sub_signed(self.column, column_offset)?,
+        })
+    }
+
+    fn out_of_range(self, operator: &str, (row_offset, column_offset): (isize, isize)) -> ! {
+        panic!(
+            "Coord {}, {} {} offset {}, {} is out of range",
+            self.row, self.column, operator, row_offset, column_offset
+        )
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((row, column): (usize, usize)) -> Self {
+        Coord { row, column }
+    }
+}
+
+impl From<Coord> for (usize, usize) {
+    fn from(coord: Coord) -> Self {
+        (coord.row, coord.column)
+    }
+}
+
+impl Add<(isize, isize)> for Coord {
+    type Output = Coord;
+
+    /// Moves by a signed `(row, column)` offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index would be negative or would overflow. See
+    /// [`checked_add`] for a version that returns an [`Option`] instead.
+    ///
+    /// [`checked_add`]: struct.Coord.html#method.checked_add
+    /// [`Option`]: https://doc.rust-lang.org/std/option/
+    fn add(self, offset: (isize, isize)) -> Self::Output {
+        self.checked_add(offset)
+            .unwrap_or_else(|| self.out_of_range("+", offset))
+    }
+}
+
+impl Sub<(isize, isize)> for Coord {
+    type Output = Coord;
+
+    /// Moves by the negation of a signed `(row, column)` offset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index would be negative or would overflow.
+    fn sub(self, offset: (isize, isize)) -> Self::Output {
+        self.checked_sub(offset)
+            .unwrap_or_else(|| self.out_of_range("-", offset))
+    }
+}
+
+impl Add<Direction> for Coord {
+    type Output = Coord;
+
+    /// Moves one step in `direction`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index would be negative.
+    fn add(self, direction: Direction) -> Self::Output {
+        self + direction.delta()
+    }
+}
+
+impl Sub<Direction> for Coord {
+    type Output = Coord;
+
+    /// Moves one step in the opposite of `direction`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index would be negative.
+    fn sub(self, direction: Direction) -> Self::Output {
+        self - direction.delta()
+    }
+}
+
+/// One of the eight directions from an element to its neighbors. [`North`]
+/// points towards the first row and [`West`] towards the first column.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::Direction;
+/// let mut direction = Direction::North;
+/// direction = direction.rotate_cw();
+/// assert_eq!(direction, Direction::East);
+/// assert_eq!(direction.delta(), (0, 1));
+/// assert_eq!(direction.opposite(), Direction::West);
+/// assert_eq!(Direction::SouthWest.delta(), (1, -1));
+/// ```
+///
+/// [`North`]: enum.Direction.html#variant.North
+/// [`West`]: enum.Direction.html#variant.West
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub enum Direction {
+    /// The previous row.
+    North,
+    /// The previous row and the next column.
+    NorthEast,
+    /// The next column.
+    East,
+    /// The next row and the next column.
+    SouthEast,
+    /// The next row.
+    South,
+    /// The next row and the previous column.
+    SouthWest,
+    /// The previous column.
+    West,
+    /// The previous row and the previous column.
+    NorthWest,
+}
+
+impl Direction {
+    /// All eight directions, clockwise from [`North`].
+    ///
+    /// [`North`]: enum.Direction.html#variant.North
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The four cardinal directions, clockwise from [`North`].
+    ///
+    /// [`North`]: enum.Direction.html#variant.North
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Returns the `(row, column)` offset of one step in this direction.
+    pub const fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    /// Returns the direction a quarter turn clockwise from this one.
+    pub const fn rotate_cw(self) -> Direction {
+        self.turn(2)
+    }
+
+    /// Returns the direction a quarter turn counterclockwise from this one.
+    pub const fn rotate_ccw(self) -> Direction {
+        self.turn(6)
+    }
+
+    /// Returns the direction pointing the opposite way.
+    pub const fn opposite(self) -> Direction {
+        self.turn(4)
+    }
+
+    /// Returns `true` for the four directions that change both the row and
+    /// the column.
+    pub const fn is_diagonal(self) -> bool {
+        (self as usize) % 2 == 1
+    }
+
+    /// Turns clockwise by the given number of eighths of a full turn.
+    const fn turn(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+}
+
+fn checked_sub_signed(index: usize, offset: isize) -> Option<usize> {
+    if offset >= 0 {
+        index.checked_sub(offset.unsigned_abs())
+    } else {
+        index.checked_add(offset.unsigned_abs())
+    }
+}
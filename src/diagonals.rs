@@ -0,0 +1,363 @@
+//! Iterators over the diagonals and anti-diagonals of an [`Array2D`].
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{Array2D, DiagonalIter, DiagonalIterMut, Error, Storage, StorageMut};
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns an [`Iterator`] over references to all elements in the
+    /// diagonal at the given `offset` from the main diagonal, going from top
+    /// left to bottom right. An offset of `0` is the main diagonal, which
+    /// starts at `(0, 0)`. Positive offsets are above and to the right of the
+    /// main diagonal, and negative offsets are below and to the left of it.
+    ///
+    /// Returns an error if the diagonal is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let array = Array2D::from_rows(&rows)?;
+    /// assert_eq!(array.diagonal(0)?.collect::<Vec<_>>(), vec![&1, &5]);
+    /// assert_eq!(array.diagonal(1)?.collect::<Vec<_>>(), vec![&2, &6]);
+    /// assert_eq!(array.diagonal(-1)?.collect::<Vec<_>>(), vec![&4]);
+    /// assert_eq!(
+    ///     array.diagonal(3).err(),
+    ///     Some(Error::DiagonalOutOfBounds {
+    ///         offset: 3,
+    ///         num_rows: 2,
+    ///         num_columns: 3
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn diagonal(&self, offset: isize) -> Result<DiagonalIter<'_, T>, Error> {
+        self.diagonal_in(offset, Orientation::Diagonal)
+    }
+
+    /// Returns an [`Iterator`] over references to all elements in the
+    /// anti-diagonal at the given `offset` from the main anti-diagonal, going
+    /// from top right to bottom left. An offset of `0` is the main
+    /// anti-diagonal, which starts in the top right corner. Positive offsets
+    /// are above and to the left of the main anti-diagonal, and negative
+    /// offsets are below and to the right of it.
+    ///
+    /// In other words, this is the same as [`diagonal`] on a copy of the
+    /// array with the order of the columns reversed. Returns the same error
+    /// as [`diagonal`] if the anti-diagonal is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let array = Array2D::from_rows(&rows)?;
+    /// assert_eq!(array.anti_diagonal(0)?.collect::<Vec<_>>(), vec![&3, &5]);
+    /// assert_eq!(array.anti_diagonal(1)?.collect::<Vec<_>>(), vec![&2, &4]);
+    /// assert_eq!(array.anti_diagonal(-1)?.collect::<Vec<_>>(), vec![&6]);
+    /// assert!(array.anti_diagonal(-2).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`diagonal`]: struct.Array2D.html#method.diagonal
+    pub fn anti_diagonal(&self, offset: isize) -> Result<DiagonalIter<'_, T>, Error> {
+        self.diagonal_in(offset, Orientation::AntiDiagonal)
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in the
+    /// diagonal at the given `offset`. See [`diagonal`] for how the offset is
+    /// interpreted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(3, 3, 0);
+    /// for element in array.diagonal_mut(0)? {
+    ///     *element = 1;
+    /// }
+    /// assert_eq!(
+    ///     array.as_rows(),
+    ///     vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`diagonal`]: struct.Array2D.html#method.diagonal
+    pub fn diagonal_mut(&mut self, offset: isize) -> Result<DiagonalIterMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        self.diagonal_mut_in(offset, Orientation::Diagonal)
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in the
+    /// anti-diagonal at the given `offset`. See [`anti_diagonal`] for how the
+    /// offset is interpreted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(3, 3, 0);
+    /// for element in array.anti_diagonal_mut(0)? {
+    ///     *element = 1;
+    /// }
+    /// assert_eq!(
+    ///     array.as_rows(),
+    ///     vec![vec![0, 0, 1], vec![0, 1, 0], vec![1, 0, 0]]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`anti_diagonal`]: struct.Array2D.html#method.anti_diagonal
+    pub fn anti_diagonal_mut(&mut self, offset: isize) -> Result<DiagonalIterMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        self.diagonal_mut_in(offset, Orientation::AntiDiagonal)
+    }
+
+    /// Returns an [`Iterator`] over all diagonals, in order of increasing
+    /// offset, i.e. starting with the bottom left corner and ending with the
+    /// top right corner. Each [`Item`] is itself another [`Iterator`] over
+    /// references to the elements in that diagonal (see [`diagonal`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let array = Array2D::from_rows(&rows)?;
+    /// let diagonals = array
+    ///     .diagonals_iter()
+    ///     .map(|diagonal| diagonal.cloned().collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(diagonals, vec![vec![4], vec![1, 5], vec![2, 6], vec![3]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`diagonal`]: struct.Array2D.html#method.diagonal
+    pub fn diagonals_iter(&self) -> impl DoubleEndedIterator<Item = DiagonalIter<'_, T>> + Clone {
+        self.diagonal_offsets().map(move |offset| {
+            self.diagonal(offset)
+                .expect("diagonals_iter should never fail")
+        })
+    }
+
+    /// Returns an [`Iterator`] over all anti-diagonals, in order of increasing
+    /// offset, i.e. starting with the bottom right corner and ending with the
+    /// top left corner. Each [`Item`] is itself another [`Iterator`] over
+    /// references to the elements in that anti-diagonal (see
+    /// [`anti_diagonal`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let array = Array2D::from_rows(&rows)?;
+    /// let anti_diagonals = array
+    ///     .anti_diagonals_iter()
+    ///     .map(|anti_diagonal| anti_diagonal.cloned().collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(anti_diagonals, vec![vec![6], vec![3, 5], vec![2, 4], vec![1]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`anti_diagonal`]: struct.Array2D.html#method.anti_diagonal
+    pub fn anti_diagonals_iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = DiagonalIter<'_, T>> + Clone {
+        self.diagonal_offsets().map(move |offset| {
+            self.anti_diagonal(offset)
+                .expect("anti_diagonals_iter should never fail")
+        })
+    }
+
+    /// Returns an [`Iterator`] over all diagonals, in the same order as
+    /// [`diagonals_iter`]. Each [`Item`] is itself another [`Iterator`] over
+    /// mutable references to the elements in that diagonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(2, 3, 0);
+    /// for (index, diagonal) in array.diagonals_iter_mut().enumerate() {
+    ///     for element in diagonal {
+    ///         *element = index;
+    ///     }
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![0, 1, 2]]);
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`diagonals_iter`]: struct.Array2D.html#method.diagonals_iter
+    pub fn diagonals_iter_mut(&mut self) -> impl DoubleEndedIterator<Item = DiagonalIterMut<'_, T>>
+    where
+        S: StorageMut<T>,
+    {
+        self.diagonals_iter_mut_in(Orientation::Diagonal)
+    }
+
+    /// Returns an [`Iterator`] over all anti-diagonals, in the same order as
+    /// [`anti_diagonals_iter`]. Each [`Item`] is itself another [`Iterator`]
+    /// over mutable references to the elements in that anti-diagonal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(2, 3, 0);
+    /// for (index, anti_diagonal) in array.anti_diagonals_iter_mut().enumerate() {
+    ///     for element in anti_diagonal {
+    ///         *element = index;
+    ///     }
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![3, 2, 1], vec![2, 1, 0]]);
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`anti_diagonals_iter`]: struct.Array2D.html#method.anti_diagonals_iter
+    pub fn anti_diagonals_iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = DiagonalIterMut<'_, T>>
+    where
+        S: StorageMut<T>,
+    {
+        self.diagonals_iter_mut_in(Orientation::AntiDiagonal)
+    }
+
+    fn diagonals_iter_mut_in(
+        &mut self,
+        orientation: Orientation,
+    ) -> impl DoubleEndedIterator<Item = DiagonalIterMut<'_, T>>
+    where
+        S: StorageMut<T>,
+    {
+        let ptr = self.array.as_mut_ptr();
+        let (num_rows, num_columns, layout) = (self.num_rows, self.num_columns, self.layout);
+        let stride = orientation.stride(self.strides());
+        self.diagonal_offsets().map(move |offset| {
+            let (start, len) = diagonal_bounds(num_rows, num_columns, offset, orientation)
+                .expect("diagonals_iter_mut should never fail");
+            let index = layout.index(start.0, start.1, num_rows, num_columns);
+            // SAFETY: Every element is in bounds and belongs to exactly one
+            // diagonal, and each diagonal is produced at most once, so the
+            // mutable references never alias.
+            unsafe { DiagonalIterMut::new(ptr.add(index), len, stride) }
+        })
+    }
+
+    fn diagonal_in(
+        &self,
+        offset: isize,
+        orientation: Orientation,
+    ) -> Result<DiagonalIter<'_, T>, Error> {
+        let (start, len) = self.diagonal_bounds(offset, orientation)?;
+        let index = self.storage_index(start.0, start.1);
+        let stride = orientation.stride(self.strides());
+        // SAFETY: Every element of the diagonal is in bounds.
+        Ok(unsafe { DiagonalIter::new(self.array.as_ptr().add(index), len, stride) })
+    }
+
+    fn diagonal_mut_in(
+        &mut self,
+        offset: isize,
+        orientation: Orientation,
+    ) -> Result<DiagonalIterMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        let (start, len) = self.diagonal_bounds(offset, orientation)?;
+        let index = self.storage_index(start.0, start.1);
+        let stride = orientation.stride(self.strides());
+        // SAFETY: Every element of the diagonal is in bounds and produced at
+        // most once.
+        Ok(unsafe { DiagonalIterMut::new(self.array.as_mut_ptr().add(index), len, stride) })
+    }
+
+    /// The offsets of all non-empty diagonals, in increasing order.
+    fn diagonal_offsets(&self) -> std::ops::Range<isize> {
+        if self.num_rows == 0 || self.num_columns == 0 {
+            return 0..0;
+        }
+        -(self.num_rows as isize - 1)..self.num_columns as isize
+    }
+
+    fn diagonal_bounds(
+        &self,
+        offset: isize,
+        orientation: Orientation,
+    ) -> Result<((usize, usize), usize), Error> {
+        diagonal_bounds(self.num_rows, self.num_columns, offset, orientation)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Orientation {
+    Diagonal,
+    AntiDiagonal,
+}
+
+impl Orientation {
+    /// The distance in the buffer between neighboring elements of a diagonal,
+    /// given the `(row_stride, column_stride)` of the array.
+    fn stride(self, (row_stride, column_stride): (usize, usize)) -> isize {
+        match self {
+            Orientation::Diagonal => row_stride as isize + column_stride as isize,
+            Orientation::AntiDiagonal => row_stride as isize - column_stride as isize,
+        }
+    }
+}
+
+/// Returns the indices of the first element of the diagonal and its length.
+fn diagonal_bounds(
+    num_rows: usize,
+    num_columns: usize,
+    offset: isize,
+    orientation: Orientation,
+) -> Result<((usize, usize), usize), Error> {
+    let row = if offset < 0 { offset.unsigned_abs() } else { 0 };
+    // Counted from the left for diagonals and from the right for
+    // anti-diagonals.
+    let column = if offset > 0 { offset as usize } else { 0 };
+    if row >= num_rows || column >= num_columns {
+        return Err(Error::DiagonalOutOfBounds {
+            offset,
+            num_rows,
+            num_columns,
+        });
+    }
+    let len = (num_rows - row).min(num_columns - column);
+    let start = match orientation {
+        Orientation::Diagonal => (row, column),
+        Orientation::AntiDiagonal => (row, num_columns - 1 - column),
+    };
+    Ok((start, len))
+}
@@ -0,0 +1,258 @@
+//! Types that can be used as the index of an element of an [`Array2D`], and
+//! conversions between indices and positions in [row major or column major
+//! order].
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+//! [row major or column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+
+use crate::{Array2D, Coord, Storage};
+use std::convert::TryInto;
+
+/// A type that can be used as the index of an element of an [`Array2D`], such
+/// as a `(row, column)` tuple, a `[row, column]` array or a [`Coord`].
+///
+/// Tuples and arrays of any primitive integer type can be used, so positions
+/// stored as e.g. `(i32, i32)` or `[u32; 2]` need no casts. A value that does
+/// not fit in a [`usize`], such as a negative one, is never in bounds, so it
+/// produces [`None`] or an [`Error::IndicesOutOfBounds`] instead of wrapping
+/// around to a valid index.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::{Array2D, Coord, Error};
+/// # fn main() -> Result<(), Error> {
+/// let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+/// assert_eq!(array[(1, 2)], 6);
+/// assert_eq!(array[[1_u32, 2]], 6);
+/// assert_eq!(array.get(Coord::new(1, 2)), Some(&6));
+/// assert_eq!(array.get((-1_i32, 2)), None);
+///
+/// array.set((0_i64, 0_i64), 10)?;
+/// assert_eq!(
+///     array.set((-1_i32, 0), 10),
+///     Err(Error::IndicesOutOfBounds {
+///         row: usize::MAX,
+///         column: 0,
+///         num_rows: 2,
+///         num_columns: 3
+///     })
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [`Coord`]: struct.Coord.html
+/// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+/// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+/// [`Error::IndicesOutOfBounds`]: enum.Error.html#variant.IndicesOutOfBounds
+pub trait Index2D {
+    /// Returns the index as a `(row, column)` tuple. A row or column that does
+    /// not fit in a [`usize`] must be returned as [`usize::MAX`], which is out
+    /// of bounds for every array.
+    ///
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    /// [`usize::MAX`]: https://doc.rust-lang.org/std/primitive.usize.html#associatedconstant.MAX
+    fn to_row_column(self) -> (usize, usize);
+}
+
+impl Index2D for Coord {
+    fn to_row_column(self) -> (usize, usize) {
+        (self.row, self.column)
+    }
+}
+
+macro_rules! impl_index_2d {
+    ($($integer:ty),*) => {
+        $(
+            impl Index2D for ($integer, $integer) {
+                fn to_row_column(self) -> (usize, usize) {
+                    (to_usize(self.0), to_usize(self.1))
+                }
+            }
+
+            impl Index2D for [$integer; 2] {
+                fn to_row_column(self) -> (usize, usize) {
+                    (to_usize(self[0]), to_usize(self[1]))
+                }
+            }
+        )*
+    };
+}
+
+impl_index_2d!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+
+fn to_usize<N: TryInto<usize>>(value: N) -> usize {
+    value.try_into().unwrap_or(usize::MAX)
+}
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns the position in [row major order] of the element at the given
+    /// `index`, or [`None`] if the index is out of bounds. This is the index
+    /// used by [`get_row_major`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let array = Array2D::filled_with(2, 3, 0);
+    /// assert_eq!(array.row_major_index((1, 2)), Some(5));
+    /// assert_eq!(array.row_major_index((2, 0)), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`get_row_major`]: struct.Array2D.html#method.get_row_major
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn row_major_index<I: Index2D>(&self, index: I) -> Option<usize> {
+        let (row, column) = index.to_row_column();
+        if row < self.num_rows && column < self.num_columns {
+            Some(row_major_index(row, column, self.num_columns))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position in [column major order] of the element at the
+    /// given `index`, or [`None`] if the index is out of bounds. This is the
+    /// index used by [`get_column_major`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let array = Array2D::filled_with(2, 3, 0);
+    /// assert_eq!(array.column_major_index((1, 2)), Some(5));
+    /// assert_eq!(array.column_major_index((0, 1)), Some(2));
+    /// assert_eq!(array.column_major_index((0, 3)), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`get_column_major`]: struct.Array2D.html#method.get_column_major
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn column_major_index<I: Index2D>(&self, index: I) -> Option<usize> {
+        let (row, column) = index.to_row_column();
+        if row < self.num_rows && column < self.num_columns {
+            Some(column_major_index(row, column, self.num_rows))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `(row, column)` of the element at the given position in
+    /// [row major order], or [`None`] if the position is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let array = Array2D::filled_with(2, 3, 0);
+    /// assert_eq!(array.coords_from_row_major(4), Some((1, 1)));
+    /// assert_eq!(array.coords_from_row_major(6), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn coords_from_row_major(&self, index: usize) -> Option<(usize, usize)> {
+        if index < self.num_elements() {
+            Some(coords_from_row_major(index, self.num_columns))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `(row, column)` of the element at the given position in
+    /// [column major order], or [`None`] if the position is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let array = Array2D::filled_with(2, 3, 0);
+    /// assert_eq!(array.coords_from_column_major(4), Some((0, 2)));
+    /// assert_eq!(array.coords_from_column_major(6), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn coords_from_column_major(&self, index: usize) -> Option<(usize, usize)> {
+        if index < self.num_elements() {
+            Some(coords_from_column_major(index, self.num_rows))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the position in [row major order] of the element at `row` and
+/// `column` of an array with `num_columns` columns, without checking that the
+/// element is in bounds.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::row_major_index;
+/// const CENTER: usize = row_major_index(1, 1, 3);
+/// assert_eq!(CENTER, 4);
+/// ```
+///
+/// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+pub const fn row_major_index(row: usize, column: usize, num_columns: usize) -> usize {
+    row * num_columns + column
+}
+
+/// Returns the position in [column major order] of the element at `row` and
+/// `column` of an array with `num_rows` rows, without checking that the
+/// element is in bounds.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::column_major_index;
+/// assert_eq!(column_major_index(1, 2, 2), 5);
+/// ```
+///
+/// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+pub const fn column_major_index(row: usize, column: usize, num_rows: usize) -> usize {
+    column * num_rows + row
+}
+
+/// Returns the `(row, column)` of the element at the given position in [row
+/// major order] of an array with `num_columns` columns, without checking that
+/// the element is in bounds.
+///
+/// # Panics
+///
+/// Panics if `num_columns` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::coords_from_row_major;
+/// assert_eq!(coords_from_row_major(4, 3), (1, 1));
+/// ```
+///
+/// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+pub const fn coords_from_row_major(index: usize, num_columns: usize) -> (usize, usize) {
+    (index / num_columns, index % num_columns)
+}
+
+/// Returns the `(row, column)` of the element at the given position in
+/// [column major order] of an array with `num_rows` rows, without checking
+/// that the element is in bounds.
+///
+/// # Panics
+///
+/// Panics if `num_rows` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::coords_from_column_major;
+/// assert_eq!(coords_from_column_major(4, 2), (0, 2));
+/// ```
+///
+/// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+pub const fn coords_from_column_major(index: usize, num_rows: usize) -> (usize, usize) {
+    (index % num_rows, index / num_rows)
+}
@@ -0,0 +1,646 @@
+//! The [`Iterator`] types returned by the iteration methods of [`Array2D`].
+//!
+//! [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::Layout;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+/// The positions that have not been produced yet out of `0..len`, from both
+/// ends.
+#[derive(Debug, Clone)]
+pub(crate) struct Positions {
+    front: usize,
+    back: usize,
+}
+
+impl Positions {
+    pub(crate) fn new(len: usize) -> Self {
+        Positions {
+            front: 0,
+            back: len,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.back - self.front
+    }
+
+    pub(crate) fn next(&mut self) -> Option<usize> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.front - 1)
+    }
+
+    pub(crate) fn next_back(&mut self) -> Option<usize> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.back)
+    }
+
+    pub(crate) fn nth(&mut self, n: usize) -> Option<usize> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+/// Implements [`Iterator`], [`DoubleEndedIterator`], [`ExactSizeIterator`],
+/// and [`FusedIterator`] for a type with a `positions` field and an `item`
+/// method that turns a position into an item.
+macro_rules! impl_iterator {
+    (impl<$($lifetime:lifetime,)? $($param:ident)?> for $name:ty => $item:ty) => {
+        impl<$($lifetime,)? $($param)?> Iterator for $name {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let position = self.positions.next()?;
+                Some(self.item(position))
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.positions.len();
+                (len, Some(len))
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                let position = self.positions.nth(n)?;
+                Some(self.item(position))
+            }
+        }
+
+        impl<$($lifetime,)? $($param)?> DoubleEndedIterator for $name {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let position = self.positions.next_back()?;
+                Some(self.item(position))
+            }
+        }
+
+        impl<$($lifetime,)? $($param)?> ExactSizeIterator for $name {}
+
+        impl<$($lifetime,)? $($param)?> FusedIterator for $name {}
+    };
+}
+
+/// Implements [`Send`] and [`Sync`] for an iterator holding raw pointers
+/// whenever its items could be sent or shared themselves.
+macro_rules! impl_send_sync {
+    ($name:ident, $item:ty) => {
+        // SAFETY: The iterator only hands out the items it owns the right to.
+        unsafe impl<'a, T> Send for $name<'a, T> where $item: Send {}
+        // SAFETY: The iterator can only produce items through `&mut self`.
+        unsafe impl<'a, T> Sync for $name<'a, T> where $item: Sync {}
+    };
+}
+
+/// Implements [`Clone`] for a shared iterator without requiring `T: Clone`.
+macro_rules! impl_clone {
+    ($name:ident, $($field:ident),*) => {
+        impl<T> Clone for $name<'_, T> {
+            fn clone(&self) -> Self {
+                $name {
+                    $($field: self.$field.clone()),*
+                }
+            }
+        }
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Indices /////////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+/// An [`Iterator`] over the indices of an [`Array2D`] in [row major order],
+/// created by [`Array2D::indices_row_major`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::indices_row_major`]: struct.Array2D.html#method.indices_row_major
+/// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+#[derive(Debug, Clone)]
+pub struct IndicesRowMajor {
+    num_columns: usize,
+    positions: Positions,
+}
+
+impl IndicesRowMajor {
+    pub(crate) fn new(num_rows: usize, num_columns: usize) -> Self {
+        IndicesRowMajor {
+            num_columns,
+            positions: Positions::new(num_rows * num_columns),
+        }
+    }
+
+    fn item(&self, position: usize) -> (usize, usize) {
+        (position / self.num_columns, position % self.num_columns)
+    }
+}
+
+impl_iterator!(impl<> for IndicesRowMajor => (usize, usize));
+
+/// An [`Iterator`] over the indices of an [`Array2D`] in [column major
+/// order], created by [`Array2D::indices_column_major`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::indices_column_major`]: struct.Array2D.html#method.indices_column_major
+/// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+#[derive(Debug, Clone)]
+pub struct IndicesColumnMajor {
+    num_rows: usize,
+    positions: Positions,
+}
+
+impl IndicesColumnMajor {
+    pub(crate) fn new(num_rows: usize, num_columns: usize) -> Self {
+        IndicesColumnMajor {
+            num_rows,
+            positions: Positions::new(num_rows * num_columns),
+        }
+    }
+
+    fn item(&self, position: usize) -> (usize, usize) {
+        (position % self.num_rows, position / self.num_rows)
+    }
+}
+
+impl_iterator!(impl<> for IndicesColumnMajor => (usize, usize));
+
+////////////////////////////////////////////////////////////////////////////////
+// Rows and Columns ////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+/// Defines an iterator over elements that are a fixed `stride` apart in
+/// memory, such as a single row or column.
+macro_rules! strided_iter {
+    ($(#[$attr:meta])* $name:ident, $ptr:ty, $item:ty, |$element:ident| $deref:expr) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name<'a, T> {
+            ptr: $ptr,
+            stride: usize,
+            positions: Positions,
+            marker: PhantomData<$item>,
+        }
+
+        impl<'a, T> $name<'a, T> {
+            /// # Safety
+            ///
+            /// `ptr + index * stride` must point to a distinct element that
+            /// is valid for `'a` for every `index < len`.
+            pub(crate) unsafe fn new(ptr: $ptr, len: usize, stride: usize) -> Self {
+                $name {
+                    ptr,
+                    stride,
+                    positions: Positions::new(len),
+                    marker: PhantomData,
+                }
+            }
+
+            fn item(&self, position: usize) -> $item {
+                // SAFETY: Every position is in bounds and produced at most
+                // once.
+                unsafe {
+                    let $element = self.ptr.add(position * self.stride);
+                    $deref
+                }
+            }
+        }
+
+        impl_iterator!(impl<'a, T> for $name<'a, T> => $item);
+        impl_send_sync!($name, $item);
+    };
+}
+
+strided_iter!(
+    /// An [`Iterator`] over references to the elements in one row of an
+    /// [`Array2D`], created by [`Array2D::row_iter`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::row_iter`]: struct.Array2D.html#method.row_iter
+    RowIter, *const T, &'a T, |element| &*element
+);
+
+strided_iter!(
+    /// An [`Iterator`] over references to the elements in one column of an
+    /// [`Array2D`], created by [`Array2D::column_iter`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::column_iter`]: struct.Array2D.html#method.column_iter
+    ColumnIter, *const T, &'a T, |element| &*element
+);
+
+strided_iter!(
+    /// An [`Iterator`] over mutable references to the elements in one row of
+    /// an [`Array2D`], created by [`Array2D::row_iter_mut`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::row_iter_mut`]: struct.Array2D.html#method.row_iter_mut
+    RowIterMut, *mut T, &'a mut T, |element| &mut *element
+);
+
+strided_iter!(
+    /// An [`Iterator`] over mutable references to the elements in one column
+    /// of an [`Array2D`], created by [`Array2D::column_iter_mut`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::column_iter_mut`]: struct.Array2D.html#method.column_iter_mut
+    ColumnIterMut, *mut T, &'a mut T, |element| &mut *element
+);
+
+/// Defines an iterator over lines (rows or columns) that are a fixed
+/// `line_stride` apart in memory, producing a strided iterator for each line.
+macro_rules! lines_iter {
+    ($(#[$attr:meta])* $name:ident, $ptr:ty, $line:ident) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name<'a, T> {
+            ptr: $ptr,
+            line_stride: usize,
+            line_len: usize,
+            element_stride: usize,
+            positions: Positions,
+            marker: PhantomData<$line<'a, T>>,
+        }
+
+        impl<'a, T> $name<'a, T> {
+            /// # Safety
+            ///
+            /// `ptr + line * line_stride + index * element_stride` must point
+            /// to a distinct element that is valid for `'a` for every `line <
+            /// num_lines` and `index < line_len`.
+            pub(crate) unsafe fn new(
+                ptr: $ptr,
+                num_lines: usize,
+                line_stride: usize,
+                line_len: usize,
+                element_stride: usize,
+            ) -> Self {
+                $name {
+                    ptr,
+                    line_stride,
+                    line_len,
+                    element_stride,
+                    positions: Positions::new(num_lines),
+                    marker: PhantomData,
+                }
+            }
+
+            fn item(&self, position: usize) -> $line<'a, T> {
+                // SAFETY: Every line is in bounds and produced at most once.
+                // An empty line may not have a valid start, so it is never
+                // offset.
+                unsafe {
+                    let start = if self.line_len == 0 {
+                        self.ptr
+                    } else {
+                        self.ptr.add(position * self.line_stride)
+                    };
+                    $line::new(start, self.line_len, self.element_stride)
+                }
+            }
+        }
+
+        impl_iterator!(impl<'a, T> for $name<'a, T> => $line<'a, T>);
+        impl_send_sync!($name, $line<'a, T>);
+    };
+}
+
+lines_iter!(
+    /// An [`Iterator`] over all rows of an [`Array2D`], created by
+    /// [`Array2D::rows_iter`]. Each [`Item`] is a [`RowIter`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::rows_iter`]: struct.Array2D.html#method.rows_iter
+    /// [`RowIter`]: struct.RowIter.html
+    RowsIter, *const T, RowIter
+);
+
+lines_iter!(
+    /// An [`Iterator`] over all columns of an [`Array2D`], created by
+    /// [`Array2D::columns_iter`]. Each [`Item`] is a [`ColumnIter`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::columns_iter`]: struct.Array2D.html#method.columns_iter
+    /// [`ColumnIter`]: struct.ColumnIter.html
+    ColumnsIter, *const T, ColumnIter
+);
+
+lines_iter!(
+    /// An [`Iterator`] over all rows of an [`Array2D`], created by
+    /// [`Array2D::rows_iter_mut`]. Each [`Item`] is a [`RowIterMut`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::rows_iter_mut`]: struct.Array2D.html#method.rows_iter_mut
+    /// [`RowIterMut`]: struct.RowIterMut.html
+    RowsIterMut, *mut T, RowIterMut
+);
+
+lines_iter!(
+    /// An [`Iterator`] over all columns of an [`Array2D`], created by
+    /// [`Array2D::columns_iter_mut`]. Each [`Item`] is a [`ColumnIterMut`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::columns_iter_mut`]: struct.Array2D.html#method.columns_iter_mut
+    /// [`ColumnIterMut`]: struct.ColumnIterMut.html
+    ColumnsIterMut, *mut T, ColumnIterMut
+);
+
+////////////////////////////////////////////////////////////////////////////////
+// Diagonals ///////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+/// Defines an iterator over the elements of a diagonal or anti-diagonal, which
+/// are a fixed `stride` apart in memory. The stride of an anti-diagonal is
+/// negative when the array has a column major layout.
+macro_rules! diagonal_iter {
+    ($(#[$attr:meta])* $name:ident, $ptr:ty, $item:ty, |$element:ident| $deref:expr) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name<'a, T> {
+            ptr: $ptr,
+            stride: isize,
+            positions: Positions,
+            marker: PhantomData<$item>,
+        }
+
+        impl<'a, T> $name<'a, T> {
+            /// # Safety
+            ///
+            /// `ptr + index * stride` must point to a distinct element that
+            /// is valid for `'a` for every `index < len`.
+            pub(crate) unsafe fn new(ptr: $ptr, len: usize, stride: isize) -> Self {
+                $name {
+                    ptr,
+                    stride,
+                    positions: Positions::new(len),
+                    marker: PhantomData,
+                }
+            }
+
+            fn item(&self, position: usize) -> $item {
+                // SAFETY: Every position is in bounds and produced at most
+                // once, and no buffer holds more than `isize::MAX` elements.
+                unsafe {
+                    let $element = self.ptr.offset(position as isize * self.stride);
+                    $deref
+                }
+            }
+        }
+
+        impl_iterator!(impl<'a, T> for $name<'a, T> => $item);
+        impl_send_sync!($name, $item);
+    };
+}
+
+diagonal_iter!(
+    /// An [`Iterator`] over references to the elements in one diagonal or
+    /// anti-diagonal of an [`Array2D`], created by [`Array2D::diagonal`] and
+    /// [`Array2D::anti_diagonal`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::diagonal`]: struct.Array2D.html#method.diagonal
+    /// [`Array2D::anti_diagonal`]: struct.Array2D.html#method.anti_diagonal
+    DiagonalIter, *const T, &'a T, |element| &*element
+);
+
+diagonal_iter!(
+    /// An [`Iterator`] over mutable references to the elements in one
+    /// diagonal or anti-diagonal of an [`Array2D`], created by
+    /// [`Array2D::diagonal_mut`] and [`Array2D::anti_diagonal_mut`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::diagonal_mut`]: struct.Array2D.html#method.diagonal_mut
+    /// [`Array2D::anti_diagonal_mut`]: struct.Array2D.html#method.anti_diagonal_mut
+    DiagonalIterMut, *mut T, &'a mut T, |element| &mut *element
+);
+
+////////////////////////////////////////////////////////////////////////////////
+// Elements ////////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+/// Defines an iterator over the elements of a buffer stored in a [`Layout`]
+/// that visits them in the order of another one, optionally paired with
+/// their indices.
+///
+/// [`Layout`]: enum.Layout.html
+macro_rules! elements_iter {
+    ($(#[$attr:meta])* $name:ident, $order:expr, $ptr:ty, $reference:ty, $item:ty,
+     |$index:ident, $element:ident| $make:expr) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $name<'a, T> {
+            ptr: $ptr,
+            num_rows: usize,
+            num_columns: usize,
+            layout: Layout,
+            positions: Positions,
+            marker: PhantomData<$reference>,
+        }
+
+        impl<'a, T> $name<'a, T> {
+            /// # Safety
+            ///
+            /// `ptr` must point to `num_rows * num_columns` elements stored
+            /// in `layout` that are valid for `'a`.
+            pub(crate) unsafe fn new(
+                ptr: $ptr,
+                num_rows: usize,
+                num_columns: usize,
+                layout: Layout,
+            ) -> Self {
+                $name {
+                    ptr,
+                    num_rows,
+                    num_columns,
+                    layout,
+                    positions: Positions::new(num_rows * num_columns),
+                    marker: PhantomData,
+                }
+            }
+
+            fn item(&self, position: usize) -> $item {
+                let $index = $order.coords(position, self.num_rows, self.num_columns);
+                // Visiting the elements in the order they are stored needs no
+                // conversion.
+                let offset = if self.layout == $order {
+                    position
+                } else {
+                    self.layout
+                        .index($index.0, $index.1, self.num_rows, self.num_columns)
+                };
+                // SAFETY: Every index is in bounds and produced at most once.
+                let $element = unsafe { self.ptr.add(offset) };
+                $make
+            }
+        }
+
+        impl_iterator!(impl<'a, T> for $name<'a, T> => $item);
+        impl_send_sync!($name, $item);
+    };
+}
+
+elements_iter!(
+    /// An [`Iterator`] over references to all elements of an [`Array2D`] in
+    /// [row major order], created by [`Array2D::elements_row_major_iter`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::elements_row_major_iter`]: struct.Array2D.html#method.elements_row_major_iter
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    ElementsRowMajorIter, Layout::RowMajor, *const T, &'a T, &'a T,
+    |_index, element| unsafe { &*element }
+);
+
+elements_iter!(
+    /// An [`Iterator`] over mutable references to all elements of an
+    /// [`Array2D`] in [row major order], created by
+    /// [`Array2D::elements_row_major_iter_mut`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::elements_row_major_iter_mut`]: struct.Array2D.html#method.elements_row_major_iter_mut
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    ElementsRowMajorIterMut, Layout::RowMajor, *mut T, &'a mut T, &'a mut T,
+    |_index, element| unsafe { &mut *element }
+);
+
+elements_iter!(
+    /// An [`Iterator`] over references to all elements of an [`Array2D`] in
+    /// [column major order], created by [`Array2D::elements_column_major_iter`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::elements_column_major_iter`]: struct.Array2D.html#method.elements_column_major_iter
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    ElementsColumnMajorIter, Layout::ColumnMajor, *const T, &'a T, &'a T,
+    |_index, element| unsafe { &*element }
+);
+
+elements_iter!(
+    /// An [`Iterator`] over mutable references to all elements of an
+    /// [`Array2D`] in [column major order], created by
+    /// [`Array2D::elements_column_major_iter_mut`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::elements_column_major_iter_mut`]: struct.Array2D.html#method.elements_column_major_iter_mut
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    ElementsColumnMajorIterMut, Layout::ColumnMajor, *mut T, &'a mut T, &'a mut T,
+    |_index, element| unsafe { &mut *element }
+);
+
+elements_iter!(
+    /// An [`Iterator`] over all elements of an [`Array2D`] in [row major
+    /// order] along with their indices, created by
+    /// [`Array2D::enumerate_row_major`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::enumerate_row_major`]: struct.Array2D.html#method.enumerate_row_major
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    EnumerateRowMajor, Layout::RowMajor, *const T, &'a T, ((usize, usize), &'a T),
+    |index, element| (index, unsafe { &*element })
+);
+
+elements_iter!(
+    /// An [`Iterator`] over mutable references to all elements of an
+    /// [`Array2D`] in [row major order] along with their indices, created by
+    /// [`Array2D::enumerate_row_major_mut`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::enumerate_row_major_mut`]: struct.Array2D.html#method.enumerate_row_major_mut
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    EnumerateRowMajorMut, Layout::RowMajor, *mut T, &'a mut T, ((usize, usize), &'a mut T),
+    |index, element| (index, unsafe { &mut *element })
+);
+
+elements_iter!(
+    /// An [`Iterator`] over all elements of an [`Array2D`] in [column major
+    /// order] along with their indices, created by
+    /// [`Array2D::enumerate_column_major`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Array2D::enumerate_column_major`]: struct.Array2D.html#method.enumerate_column_major
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    EnumerateColumnMajor, Layout::ColumnMajor, *const T, &'a T, ((usize, usize), &'a T),
+    |index, element| (index, unsafe { &*element })
+);
+
+impl_clone!(RowIter, ptr, stride, positions, marker);
+impl_clone!(DiagonalIter, ptr, stride, positions, marker);
+impl_clone!(ColumnIter, ptr, stride, positions, marker);
+impl_clone!(
+    RowsIter,
+    ptr,
+    line_stride,
+    line_len,
+    element_stride,
+    positions,
+    marker
+);
+impl_clone!(
+    ColumnsIter,
+    ptr,
+    line_stride,
+    line_len,
+    element_stride,
+    positions,
+    marker
+);
+impl_clone!(
+    ElementsRowMajorIter,
+    ptr,
+    num_rows,
+    num_columns,
+    layout,
+    positions,
+    marker
+);
+impl_clone!(
+    ElementsColumnMajorIter,
+    ptr,
+    num_rows,
+    num_columns,
+    layout,
+    positions,
+    marker
+);
+impl_clone!(
+    EnumerateRowMajor,
+    ptr,
+    num_rows,
+    num_columns,
+    layout,
+    positions,
+    marker
+);
+impl_clone!(
+    EnumerateColumnMajor,
+    ptr,
+    num_rows,
+    num_columns,
+    layout,
+    positions,
+    marker
+);
@@ -0,0 +1,190 @@
+//! The order in which the elements of an [`Array2D`] are stored in its
+//! buffer.
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{column_major_index, coords_from_column_major, coords_from_row_major};
+use crate::{row_major_index, Array2D, Storage, StorageMut};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The order in which the elements of an [`Array2D`] are stored in memory,
+/// either one row after another or one column after another. See
+/// [row major and column major order].
+///
+/// The layout never changes what an array contains: indexing, iteration
+/// order, equality and every conversion give the same results for both
+/// layouts. It only changes which elements are next to each other in memory,
+/// and so which of row-wise or column-wise access is faster, and the order of
+/// the elements when the array is serialized.
+///
+/// Arrays are created in [`RowMajor`] layout. Use [`into_layout`] to change
+/// the layout of an existing array.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::{Array2D, Error, Layout};
+/// # fn main() -> Result<(), Error> {
+/// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+/// assert_eq!(array.layout(), Layout::RowMajor);
+///
+/// let columns = array.clone().into_layout(Layout::ColumnMajor);
+/// assert_eq!(columns.layout(), Layout::ColumnMajor);
+/// assert_eq!(columns[(1, 0)], 4);
+/// assert_eq!(columns.as_row_major(), vec![1, 2, 3, 4, 5, 6]);
+/// assert_eq!(columns, array);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [`RowMajor`]: enum.Layout.html#variant.RowMajor
+/// [`into_layout`]: struct.Array2D.html#method.into_layout
+/// [row major and column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub enum Layout {
+    /// The elements of each row are next to each other, and the rows follow
+    /// each other.
+    #[default]
+    RowMajor,
+    /// The elements of each column are next to each other, and the columns
+    /// follow each other.
+    ColumnMajor,
+}
+
+impl Layout {
+    /// The position in this layout of the element at `row` and `column`.
+    pub(crate) fn index(
+        self,
+        row: usize,
+        column: usize,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> usize {
+        match self {
+            Layout::RowMajor => row_major_index(row, column, num_columns),
+            Layout::ColumnMajor => column_major_index(row, column, num_rows),
+        }
+    }
+
+    /// The `(row, column)` of the element at `position` in this layout. The
+    /// position must be in bounds.
+    pub(crate) fn coords(
+        self,
+        position: usize,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> (usize, usize) {
+        match self {
+            Layout::RowMajor => coords_from_row_major(position, num_columns),
+            Layout::ColumnMajor => coords_from_column_major(position, num_rows),
+        }
+    }
+
+    /// The distances in this layout between neighboring rows and between
+    /// neighboring columns, as `(row_stride, column_stride)`.
+    pub(crate) fn strides(self, num_rows: usize, num_columns: usize) -> (usize, usize) {
+        match self {
+            Layout::RowMajor => (num_columns, 1),
+            Layout::ColumnMajor => (1, num_rows),
+        }
+    }
+}
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns the [`Layout`] in which the elements are stored.
+    ///
+    /// [`Layout`]: enum.Layout.html
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Consumes the [`Array2D`] and returns one with the same elements stored
+    /// in the given [`Layout`].
+    ///
+    /// The elements are rearranged in place, so no element is cloned and the
+    /// buffer is reused. Nothing is rearranged if the array already has the
+    /// given layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error, Layout};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let array = array.into_layout(Layout::ColumnMajor);
+    /// assert_eq!(array.layout(), Layout::ColumnMajor);
+    /// assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Layout`]: enum.Layout.html
+    pub fn into_layout(mut self, layout: Layout) -> Self
+    where
+        S: StorageMut<T>,
+    {
+        let (from, num_rows, num_columns) = (self.layout, self.num_rows, self.num_columns);
+        // A single row or column is stored the same way in both layouts.
+        if from != layout && num_rows > 1 && num_columns > 1 {
+            let destination = |position| {
+                let (row, column) = from.coords(position, num_rows, num_columns);
+                layout.index(row, column, num_rows, num_columns)
+            };
+            // Follow each cycle of the permutation, swapping the element at
+            // its start into place until the start holds its own element.
+            let mut visited = vec![false; self.array.len()];
+            for start in 0..self.array.len() {
+                if visited[start] {
+                    continue;
+                }
+                visited[start] = true;
+                let mut target = destination(start);
+                while target != start {
+                    self.array.swap(start, target);
+                    visited[target] = true;
+                    target = destination(target);
+                }
+            }
+        }
+        self.layout = layout;
+        self
+    }
+
+    /// The position in the buffer of the element at `row` and `column`, which
+    /// must be in bounds.
+    pub(crate) fn storage_index(&self, row: usize, column: usize) -> usize {
+        self.layout
+            .index(row, column, self.num_rows, self.num_columns)
+    }
+
+    /// The position in the buffer of the element at `index` in `order`, which
+    /// must be in bounds.
+    pub(crate) fn storage_index_in(&self, order: Layout, index: usize) -> usize {
+        if order == self.layout {
+            index
+        } else {
+            let (row, column) = order.coords(index, self.num_rows, self.num_columns);
+            self.storage_index(row, column)
+        }
+    }
+
+    /// The position in the buffer of the element at `index` in `order`, or
+    /// [`None`] if the index is out of bounds.
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub(crate) fn checked_storage_index_in(&self, order: Layout, index: usize) -> Option<usize> {
+        if index < self.array.len() {
+            Some(self.storage_index_in(order, index))
+        } else {
+            None
+        }
+    }
+}
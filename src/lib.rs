@@ -32,11 +32,37 @@
 //!     usize)`], which panics on out-of-bounds accesses.
 //!   - Using the [`get`], [`get_mut`], and [`set`] methods, which return an
 //!     [`Option`] or a [`Result`] on out-of-bounds accesses.
+//!   - Using any [`Index2D`] with the indexing syntax and these methods, such as
+//!     a tuple or array of any integer type or a [`Coord`]. Negative indices
+//!     are out of bounds instead of wrapping around.
+//!   - Using the `unsafe` [`get_unchecked`] and [`get_unchecked_row_major`]
+//!     methods and their mutable versions, which skip the bounds check for
+//!     indices that are already known to be valid.
+//!   - Taking a rectangular region as a read-only or mutable view, with a range
+//!     of rows and a range of columns (see [`slice`] and [`slice_mut`]).
 //!   - Using the row major or column major version of these methods,
 //!     i.e. [`get_row_major`], [`get_mut_row_major`], [`set_row_major`],
 //!     [`get_column_major`], [`get_mut_column_major`],
 //!     [`set_column_major`]. These perform the same tasks as the non row/column
 //!     major methods, but take one index instead of two.
+//!   - Converting between indices and positions in row major or column major
+//!     order, either checked against an array (see [`row_major_index`] and
+//!     [`coords_from_row_major`]) or as `const` functions that only need the
+//!     dimensions (see [`array2d::row_major_index`] and
+//!     [`array2d::coords_from_row_major`]).
+//!   - Using a [`Coord`] instead of a tuple. A [`Coord`] can be moved by a
+//!     [`Direction`] or by a signed offset.
+//!   - Using signed indices or a signed offset from another index, which return
+//!     [`None`] instead of underflowing (see [`get_signed`], [`get_offset`],
+//!     and [`step`]).
+//!   - Using signed indices that wrap around the edges of the array, as on a
+//!     torus (see [`get_wrapping`] and [`Wrapping`]).
+//!   - Searching for elements that match a predicate, which returns their
+//!     indices (see [`position_row_major`], [`positions`], [`count`], and
+//!     [`max_by_key`]).
+//!   - Searching for every occurrence of a smaller array, optionally with
+//!     wildcards or in any [`Orientation`] (see [`find_pattern`],
+//!     [`find_pattern_with_wildcards`], and [`find_pattern_oriented`]).
 //!
 //! [`Array2D`] also supports several forms of iteration. You can iterate
 //! through:
@@ -44,6 +70,26 @@
 //!     [`elements_row_major_iter`] and [`elements_column_major_iter`]).
 //!   - Individual rows or columns (see [`row_iter`] and [`column_iter`]).
 //!   - All rows or all columns (see [`rows_iter`] and [`columns_iter`]).
+//!   - Individual diagonals or anti-diagonals, or all of them (see [`diagonal`],
+//!     [`anti_diagonal`], [`diagonals_iter`], and [`anti_diagonals_iter`]).
+//!   - Non-overlapping rectangular blocks, as read-only or mutable views (see
+//!     [`chunks`] and [`chunks_exact`]).
+//!   - The neighbors of an element, skipping those outside the array or
+//!     wrapping around its edges (see [`neighbors4`], [`neighbors8`],
+//!     [`neighbors_with`], and [`neighbors4_wrapping`]).
+//!   - The border of the array, a ring of elements around a given one, or all
+//!     elements in a spiral (see [`border_iter`], [`ring_iter`], and
+//!     [`spiral_iter`]).
+//!   - All the elements in the order of a [`Traversal`], such as a snake or a
+//!     Hilbert curve (see [`indices`] and [`enumerate`]). The same orders can be
+//!     used to [`map`] an array or to fill one [`from_iter`].
+//!
+//! Most of these iterators also have a version that produces mutable references
+//! (e.g. [`row_iter_mut`] and [`elements_row_major_iter_mut`]).
+//!
+//! With the `rayon` feature enabled, rows and elements can also be processed in
+//! parallel (see [`par_rows_iter`], [`par_elements_iter`], [`par_map`], and
+//! [`par_from_fn`]).
 //!
 //! ## Extracting all data from an [`Array2D`]
 //!
@@ -53,6 +99,37 @@
 //!   - A "flat" [`Vec`] of elements in either [row major or column major order]
 //!     (see [`as_row_major`] and [`as_column_major`]).
 //!
+//! Two arrays with the same dimensions can be combined element by element (see
+//! [`zip`], [`zip_with`], and [`zip_apply`]).
+//!
+//! Every element can also be changed in place, or moved out of the array by
+//! value into a new array that may reuse its allocation (see [`map_in_place`],
+//! [`map_in_place_with_index`], and [`into_map`]).
+//!
+//! Each row or column can also be summarized into a single value, giving a
+//! [`Vec`] with one value per row or column (see [`fold_rows`],
+//! [`reduce_rows`], [`map_rows`], and their column versions). [`map_rows`]
+//! passes each row to its function as an iterator rather than a slice, since
+//! rows are not contiguous in every [`Layout`]. The `_as_column` and `_as_row`
+//! variants return an [`Array2D`] instead (e.g. [`fold_rows_as_column`]).
+//!
+//! The elements are stored in row major order unless the array is converted to a
+//! column major [`Layout`], which makes going through columns faster. The layout
+//! never changes the contents of the array (see [`into_layout`]).
+//!
+//! By default an [`Array2D`] owns its elements in a [`Vec`], but it can also keep
+//! them in any other [`Storage`], such as a `Box<[T]>`, an `Arc<[T]>` or a
+//! borrowed slice. [`from_slice`] and [`from_mut_slice`] wrap an existing buffer
+//! without copying it, and every method that only reads the array works the same
+//! way whatever the storage.
+//!
+//! With the `serde` feature, an [`Array2D`] can be serialized and deserialized,
+//! and deserializing fails if the number of elements does not match the
+//! dimensions. With the `bevy_reflect` feature, an [`Array2D`] is reflected as a
+//! single opaque value instead of a struct, so its elements and dimensions can
+//! only be replaced together. This is a breaking change from version 0.3.2, which
+//! reflected each field separately.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -139,25 +216,93 @@
 //! [`get_column_major`]: struct.Array2D.html#method.get_column_major
 //! [`get_mut_column_major`]: struct.Array2D.html#method.get_mut_column_major
 //! [`set_column_major`]: struct.Array2D.html#method.set_column_major
+//! [`get_signed`]: struct.Array2D.html#method.get_signed
+//! [`get_offset`]: struct.Array2D.html#method.get_offset
+//! [`step`]: struct.Array2D.html#method.step
+//! [`get_wrapping`]: struct.Array2D.html#method.get_wrapping
+//! [`Wrapping`]: struct.Wrapping.html
+//! [`Coord`]: struct.Coord.html
+//! [`Index2D`]: trait.Index2D.html
+//! [`get_unchecked`]: struct.Array2D.html#method.get_unchecked
+//! [`get_unchecked_row_major`]: struct.Array2D.html#method.get_unchecked_row_major
+//! [`slice`]: struct.Array2D.html#method.slice
+//! [`row_major_index`]: struct.Array2D.html#method.row_major_index
+//! [`coords_from_row_major`]: struct.Array2D.html#method.coords_from_row_major
+//! [`Layout`]: enum.Layout.html
+//! [`into_layout`]: struct.Array2D.html#method.into_layout
+//! [`Storage`]: trait.Storage.html
+//! [`from_slice`]: struct.Array2D.html#method.from_slice
+//! [`from_mut_slice`]: struct.Array2D.html#method.from_mut_slice
+//! [`array2d::row_major_index`]: fn.row_major_index.html
+//! [`array2d::coords_from_row_major`]: fn.coords_from_row_major.html
+//! [`slice_mut`]: struct.Array2D.html#method.slice_mut
+//! [`Direction`]: enum.Direction.html
+//! [`position_row_major`]: struct.Array2D.html#method.position_row_major
+//! [`positions`]: struct.Array2D.html#method.positions
+//! [`count`]: struct.Array2D.html#method.count
+//! [`max_by_key`]: struct.Array2D.html#method.max_by_key
+//! [`find_pattern`]: struct.Array2D.html#method.find_pattern
+//! [`find_pattern_with_wildcards`]: struct.Array2D.html#method.find_pattern_with_wildcards
+//! [`find_pattern_oriented`]: struct.Array2D.html#method.find_pattern_oriented
+//! [`Orientation`]: enum.Orientation.html
 //! [`elements_row_major_iter`]: struct.Array2D.html#method.elements_row_major_iter
 //! [`elements_column_major_iter`]: struct.Array2D.html#method.elements_column_major_iter
 //! [`row_iter`]: struct.Array2D.html#method.row_iter
 //! [`column_iter`]: struct.Array2D.html#method.column_iter
 //! [`rows_iter`]: struct.Array2D.html#method.rows_iter
 //! [`columns_iter`]: struct.Array2D.html#method.columns_iter
+//! [`row_iter_mut`]: struct.Array2D.html#method.row_iter_mut
+//! [`elements_row_major_iter_mut`]: struct.Array2D.html#method.elements_row_major_iter_mut
+//! [`par_rows_iter`]: struct.Array2D.html#method.par_rows_iter
+//! [`par_elements_iter`]: struct.Array2D.html#method.par_elements_iter
+//! [`par_map`]: struct.Array2D.html#method.par_map
+//! [`par_from_fn`]: struct.Array2D.html#method.par_from_fn
+//! [`diagonal`]: struct.Array2D.html#method.diagonal
+//! [`anti_diagonal`]: struct.Array2D.html#method.anti_diagonal
+//! [`diagonals_iter`]: struct.Array2D.html#method.diagonals_iter
+//! [`anti_diagonals_iter`]: struct.Array2D.html#method.anti_diagonals_iter
+//! [`chunks`]: struct.Array2D.html#method.chunks
+//! [`chunks_exact`]: struct.Array2D.html#method.chunks_exact
+//! [`neighbors4`]: struct.Array2D.html#method.neighbors4
+//! [`neighbors8`]: struct.Array2D.html#method.neighbors8
+//! [`neighbors_with`]: struct.Array2D.html#method.neighbors_with
+//! [`neighbors4_wrapping`]: struct.Array2D.html#method.neighbors4_wrapping
+//! [`border_iter`]: struct.Array2D.html#method.border_iter
+//! [`ring_iter`]: struct.Array2D.html#method.ring_iter
+//! [`spiral_iter`]: struct.Array2D.html#method.spiral_iter
+//! [`Traversal`]: enum.Traversal.html
+//! [`indices`]: struct.Array2D.html#method.indices
+//! [`enumerate`]: struct.Array2D.html#method.enumerate
+//! [`map`]: struct.Array2D.html#method.map
+//! [`from_iter`]: struct.Array2D.html#method.from_iter
 //! [`as_rows`]: struct.Array2D.html#method.as_rows
 //! [`as_columns`]: struct.Array2D.html#method.as_columns
 //! [`as_row_major`]: struct.Array2D.html#method.as_row_major
 //! [`as_column_major`]: struct.Array2D.html#method.as_column_major
+//! [`zip`]: struct.Array2D.html#method.zip
+//! [`zip_with`]: struct.Array2D.html#method.zip_with
+//! [`zip_apply`]: struct.Array2D.html#method.zip_apply
+//! [`map_in_place`]: struct.Array2D.html#method.map_in_place
+//! [`map_in_place_with_index`]: struct.Array2D.html#method.map_in_place_with_index
+//! [`into_map`]: struct.Array2D.html#method.into_map
+//! [`fold_rows`]: struct.Array2D.html#method.fold_rows
+//! [`reduce_rows`]: struct.Array2D.html#method.reduce_rows
+//! [`map_rows`]: struct.Array2D.html#method.map_rows
+//! [`fold_rows_as_column`]: struct.Array2D.html#method.fold_rows_as_column
 //! [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
 //! [`Option`]: https://doc.rust-lang.org/std/option/
+//! [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
 //! [`Result`]: https://doc.rust-lang.org/std/result/
 //! [`(usize, usize)`]: https://doc.rust-lang.org/std/primitive.usize.html
 //! [row major or column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
 
 #![deny(missing_docs)]
 
-use std::fmt::{Display, Formatter};
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 use std::ops::{Index, IndexMut};
 
 #[cfg(feature = "bevy_reflect")]
@@ -165,40 +310,329 @@ use bevy_reflect::Reflect;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+pub use chunks::{Chunks, ChunksExact, ChunksExactMut, ChunksMut};
+pub use coord::{Coord, Direction};
+pub use index::{
+    column_major_index, coords_from_column_major, coords_from_row_major, row_major_index, Index2D,
+};
+pub use iter::{
+    ColumnIter, ColumnIterMut, ColumnsIter, ColumnsIterMut, DiagonalIter, DiagonalIterMut,
+    ElementsColumnMajorIter, ElementsColumnMajorIterMut, ElementsRowMajorIter,
+    ElementsRowMajorIterMut, EnumerateColumnMajor, EnumerateRowMajor, EnumerateRowMajorMut,
+    IndicesColumnMajor, IndicesRowMajor, RowIter, RowIterMut, RowsIter, RowsIterMut,
+};
+pub use layout::Layout;
+pub use neighbors::{NeighborIndices, Neighbors, NeighborsMut};
+pub use pattern::{Orientation, OrientedPatternMatches, PatternMatches};
+pub use perimeter::{PerimeterIter, SpiralIter};
+pub use search::PositionsIter;
+pub use storage::{Storage, StorageMut};
+pub use traversal::{Enumerate, Indices, Traversal};
+pub use view::{ArrayView, ArrayViewMut};
+pub use wrapping::Wrapping;
+pub use zip::Zip;
+
+mod axes;
+mod chunks;
+mod coord;
+mod diagonals;
+mod index;
+mod iter;
+mod layout;
+mod neighbors;
+#[cfg(feature = "rayon")]
+mod par;
+mod pattern;
+mod perimeter;
+mod search;
+mod storage;
+mod traversal;
+mod view;
+mod wrapping;
+mod zip;
+
 /// A fixed sized two-dimensional array.
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+///
+/// The elements are kept in a [`Vec`] unless another [`Storage`] is given,
+/// such as a borrowed slice (see [`from_slice`]).
+///
+/// Two arrays are equal if they have the same dimensions and the same
+/// elements, whatever their [`Layout`] or [`Storage`].
+///
+/// With the `serde` feature, deserializing an array fails if the number of
+/// elements does not match its dimensions.
+/// With the `bevy_reflect` feature, an array is reflected as an opaque value
+/// rather than a struct, so that its elements and dimensions cannot be changed
+/// separately.
+///
+/// [`Layout`]: enum.Layout.html
+/// [`Storage`]: trait.Storage.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`from_slice`]: struct.Array2D.html#method.from_slice
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
-pub struct Array2D<T> {
-    array: Vec<T>,
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "RawArray2D<S>",
+        bound(deserialize = "S: Storage<T> + Deserialize<'de>")
+    )
+)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect_value(where S: Clone))]
+pub struct Array2D<T, S = Vec<T>> {
+    array: S,
     num_rows: usize,
     num_columns: usize,
+    layout: Layout,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    marker: PhantomData<T>,
+}
+
+/// The fields of a serialized [`Array2D`], which are checked before an array
+/// is built from them.
+///
+/// [`Array2D`]: struct.Array2D.html
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "Array2D")]
+struct RawArray2D<S> {
+    array: S,
+    num_rows: usize,
+    num_columns: usize,
+    #[serde(default)]
+    layout: Layout,
+}
+
+#[cfg(feature = "serde")]
+impl<T, S: Storage<T>> TryFrom<RawArray2D<S>> for Array2D<T, S> {
+    type Error = Error;
+
+    fn try_from(raw: RawArray2D<S>) -> Result<Self, Error> {
+        let array = Array2D::from_storage(raw.num_rows, raw.num_columns, raw.array)?;
+        Ok(Array2D {
+            layout: raw.layout,
+            ..array
+        })
+    }
 }
 
 /// An error that can arise during the use of an [`Array2D`].
 ///
+/// Each variant carries the offending index or length along with the bound it
+/// was checked against. More variants may be added in the future, so matches
+/// on this enum need a wildcard arm.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::{Array2D, Error};
+/// let array = Array2D::filled_with(2, 3, 0);
+/// let error = array.column_iter(5).unwrap_err();
+/// assert_eq!(
+///     error,
+///     Error::ColumnOutOfBounds {
+///         column: 5,
+///         num_columns: 3
+///     }
+/// );
+/// assert_eq!(
+///     error.to_string(),
+///     "column 5 out of bounds for array with 3 columns"
+/// );
+/// ```
+///
 /// [`Array2D`]: struct.Array2D.html
 #[derive(Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Error {
-    /// The given indices were out of bounds.
-    IndicesOutOfBounds(usize, usize),
-    /// The given index in row or column major order was out of bounds.
-    IndexOutOfBounds(usize),
-    /// The dimensions given did not match the elements provided
-    DimensionMismatch,
+    /// The given indices were out of bounds for an array with the given
+    /// dimensions.
+    ///
+    /// A row or column given as an [`Index2D`] that does not fit in a
+    /// `usize`, such as a negative one, is reported as `usize::MAX`.
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    IndicesOutOfBounds {
+        /// The row of the given indices, or `usize::MAX` if it does not fit
+        /// in a `usize`.
+        row: usize,
+        /// The column of the given indices, or `usize::MAX` if it does not
+        /// fit in a `usize`.
+        column: usize,
+        /// The number of rows of the array.
+        num_rows: usize,
+        /// The number of columns of the array.
+        num_columns: usize,
+    },
+    /// The given index in row or column major order was out of bounds for an
+    /// array with the given number of elements.
+    IndexOutOfBounds {
+        /// The given index.
+        index: usize,
+        /// The number of elements of the array.
+        num_elements: usize,
+    },
+    /// The given row index was out of bounds.
+    RowOutOfBounds {
+        /// The given row index.
+        row: usize,
+        /// The number of rows of the array.
+        num_rows: usize,
+    },
+    /// The given column index was out of bounds.
+    ColumnOutOfBounds {
+        /// The given column index.
+        column: usize,
+        /// The number of columns of the array.
+        num_columns: usize,
+    },
+    /// The given range of rows was decreasing or extended past the last row.
+    RowRangeOutOfBounds {
+        /// The first row of the range.
+        start: usize,
+        /// The row after the last row of the range.
+        end: usize,
+        /// The number of rows of the array.
+        num_rows: usize,
+    },
+    /// The given range of columns was decreasing or extended past the last
+    /// column.
+    ColumnRangeOutOfBounds {
+        /// The first column of the range.
+        start: usize,
+        /// The column after the last column of the range.
+        end: usize,
+        /// The number of columns of the array.
+        num_columns: usize,
+    },
+    /// The number of elements provided, or the length of one of the rows or
+    /// columns provided, did not match the dimensions.
+    DimensionMismatch {
+        /// The length required by the dimensions.
+        expected: usize,
+        /// The length that was provided.
+        actual: usize,
+    },
+    /// Two arrays that must have the same dimensions did not. The dimensions
+    /// are given as `(num_rows, num_columns)`.
+    ShapeMismatch {
+        /// The dimensions of the array the method was called on.
+        expected: (usize, usize),
+        /// The dimensions of the other array.
+        actual: (usize, usize),
+    },
     /// There were not enough elements to fill the array.
-    NotEnoughElements,
+    NotEnoughElements {
+        /// The number of elements needed to fill the array.
+        expected: usize,
+        /// The number of elements that were provided.
+        actual: usize,
+    },
+    /// The same indices were given more than once where distinct indices were
+    /// required.
+    DuplicateIndices {
+        /// The row of the repeated indices.
+        row: usize,
+        /// The column of the repeated indices.
+        column: usize,
+    },
+    /// The number of elements of an array with the given dimensions does not
+    /// fit in a `usize`.
+    DimensionOverflow {
+        /// The given number of rows.
+        num_rows: usize,
+        /// The given number of columns.
+        num_columns: usize,
+    },
+    /// The diagonal or anti-diagonal at the given offset from the main one
+    /// has no elements.
+    DiagonalOutOfBounds {
+        /// The given offset.
+        offset: isize,
+        /// The number of rows of the array.
+        num_rows: usize,
+        /// The number of columns of the array.
+        num_columns: usize,
+    },
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Error::IndicesOutOfBounds(row, column) => {
-                write!(f, "indices ({row}, {column}) out of bounds")
+            Error::IndicesOutOfBounds {
+                row,
+                column,
+                num_rows,
+                num_columns,
+            } => write!(
+                f,
+                "indices ({row}, {column}) out of bounds for array with {num_rows} rows and \
+                 {num_columns} columns"
+            ),
+            Error::IndexOutOfBounds {
+                index,
+                num_elements,
+            } => write!(
+                f,
+                "index {index} out of bounds for array with {num_elements} elements"
+            ),
+            Error::RowOutOfBounds { row, num_rows } => {
+                write!(f, "row {row} out of bounds for array with {num_rows} rows")
             }
-            Error::IndexOutOfBounds(index) => write!(f, "index {index} out of bounds"),
-            Error::DimensionMismatch => write!(f, "dimension mismatch"),
-            Error::NotEnoughElements => write!(f, "not enough elements"),
+            Error::ColumnOutOfBounds {
+                column,
+                num_columns,
+            } => write!(
+                f,
+                "column {column} out of bounds for array with {num_columns} columns"
+            ),
+            Error::RowRangeOutOfBounds {
+                start,
+                end,
+                num_rows,
+            } => write!(
+                f,
+                "row range {start}..{end} out of bounds for array with {num_rows} rows"
+            ),
+            Error::ColumnRangeOutOfBounds {
+                start,
+                end,
+                num_columns,
+            } => write!(
+                f,
+                "column range {start}..{end} out of bounds for array with {num_columns} columns"
+            ),
+            Error::DimensionMismatch { expected, actual } => write!(
+                f,
+                "dimension mismatch: expected length {expected} but got {actual}"
+            ),
+            Error::ShapeMismatch { expected, actual } => write!(
+                f,
+                "shape mismatch: expected {} rows and {} columns but got {} rows and {} columns",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+            Error::NotEnoughElements { expected, actual } => write!(
+                f,
+                "not enough elements: expected {expected} but got {actual}"
+            ),
+            Error::DuplicateIndices { row, column } => {
+                write!(f, "indices ({row}, {column}) given more than once")
+            }
+            Error::DimensionOverflow {
+                num_rows,
+                num_columns,
+            } => write!(
+                f,
+                "array with {num_rows} rows and {num_columns} columns has too many elements"
+            ),
+            Error::DiagonalOutOfBounds {
+                offset,
+                num_rows,
+                num_columns,
+            } => write!(
+                f,
+                "diagonal offset {offset} out of bounds for array with {num_rows} rows and \
+                 {num_columns} columns"
+            ),
         }
     }
 }
@@ -231,13 +665,18 @@ impl<T> Array2D<T> {
         T: Clone,
     {
         let row_len = elements.first().map(Vec::len).unwrap_or(0);
-        if !elements.iter().all(|row| row.len() == row_len) {
-            return Err(Error::DimensionMismatch);
+        if let Some(row) = elements.iter().find(|row| row.len() != row_len) {
+            return Err(Error::DimensionMismatch {
+                expected: row_len,
+                actual: row.len(),
+            });
         }
         Ok(Array2D {
             array: flatten(elements),
             num_rows: elements.len(),
             num_columns: row_len,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
         })
     }
 
@@ -266,8 +705,11 @@ impl<T> Array2D<T> {
         T: Clone,
     {
         let column_len = elements.first().map(Vec::len).unwrap_or(0);
-        if !elements.iter().all(|column| column.len() == column_len) {
-            return Err(Error::DimensionMismatch);
+        if let Some(column) = elements.iter().find(|column| column.len() != column_len) {
+            return Err(Error::DimensionMismatch {
+                expected: column_len,
+                actual: column.len(),
+            });
         }
         let num_rows = column_len;
         let num_columns = elements.len();
@@ -278,6 +720,8 @@ impl<T> Array2D<T> {
             array,
             num_rows,
             num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
         })
     }
 
@@ -311,14 +755,22 @@ impl<T> Array2D<T> {
     where
         T: Clone,
     {
-        let total_len = num_rows * num_columns;
+        let total_len = total_len(num_rows, num_columns).ok_or(Error::DimensionOverflow {
+            num_rows,
+            num_columns,
+        })?;
         if total_len != elements.len() {
-            return Err(Error::DimensionMismatch);
+            return Err(Error::DimensionMismatch {
+                expected: total_len,
+                actual: elements.len(),
+            });
         }
         Ok(Array2D {
             array: elements.to_vec(),
             num_rows,
             num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
         })
     }
 
@@ -352,9 +804,15 @@ impl<T> Array2D<T> {
     where
         T: Clone,
     {
-        let total_len = num_rows * num_columns;
+        let total_len = total_len(num_rows, num_columns).ok_or(Error::DimensionOverflow {
+            num_rows,
+            num_columns,
+        })?;
         if total_len != elements.len() {
-            return Err(Error::DimensionMismatch);
+            return Err(Error::DimensionMismatch {
+                expected: total_len,
+                actual: elements.len(),
+            });
         }
         let indices_row_major =
             (0..num_rows).flat_map(move |row| (0..num_columns).map(move |column| (row, column)));
@@ -368,6 +826,8 @@ impl<T> Array2D<T> {
             array,
             num_rows,
             num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
         })
     }
 
@@ -387,12 +847,14 @@ impl<T> Array2D<T> {
     where
         T: Clone,
     {
-        let total_len = num_rows * num_columns;
+        let total_len = total_len(num_rows, num_columns).expect("array dimensions overflow usize");
         let array = vec![element; total_len];
         Array2D {
             array,
             num_rows,
             num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
         }
     }
 
@@ -420,12 +882,14 @@ impl<T> Array2D<T> {
     where
         F: FnMut() -> T,
     {
-        let total_len = num_rows * num_columns;
+        let total_len = total_len(num_rows, num_columns).expect("array dimensions overflow usize");
         let array = (0..total_len).map(|_| generator()).collect();
         Array2D {
             array,
             num_rows,
             num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
         }
     }
 
@@ -454,7 +918,7 @@ impl<T> Array2D<T> {
         F: FnMut() -> T,
         T: Clone,
     {
-        let total_len = num_rows * num_columns;
+        let total_len = total_len(num_rows, num_columns).expect("array dimensions overflow usize");
         let array_column_major = (0..total_len).map(|_| generator()).collect::<Vec<_>>();
         Array2D::from_column_major(num_rows, num_columns, &array_column_major)
             .expect("Filled by should never fail")
@@ -490,15 +954,23 @@ impl<T> Array2D<T> {
     where
         I: Iterator<Item = T>,
     {
-        let total_len = num_rows * num_columns;
+        let total_len = total_len(num_rows, num_columns).ok_or(Error::DimensionOverflow {
+            num_rows,
+            num_columns,
+        })?;
         let array = iterator.take(total_len).collect::<Vec<_>>();
         if array.len() != total_len {
-            return Err(Error::NotEnoughElements);
+            return Err(Error::NotEnoughElements {
+                expected: total_len,
+                actual: array.len(),
+            });
         }
         Ok(Array2D {
             array,
             num_rows,
             num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
         })
     }
 
@@ -533,12 +1005,22 @@ impl<T> Array2D<T> {
         I: Iterator<Item = T>,
         T: Clone,
     {
-        let total_len = num_rows * num_columns;
+        let total_len = total_len(num_rows, num_columns).ok_or(Error::DimensionOverflow {
+            num_rows,
+            num_columns,
+        })?;
         let array_column_major = iterator.take(total_len).collect::<Vec<_>>();
+        if array_column_major.len() != total_len {
+            return Err(Error::NotEnoughElements {
+                expected: total_len,
+                actual: array_column_major.len(),
+            });
+        }
         Array2D::from_column_major(num_rows, num_columns, &array_column_major)
-            .map_err(|_| Error::NotEnoughElements)
     }
+}
 
+impl<T, S: Storage<T>> Array2D<T, S> {
     /// Creates a new [`Array2D`] from an existing [`Array2D`]. Each element is traversed in [row major order]. The
     /// element is passed to `mapper`, which produces the new element to use in the new array.
     ///
@@ -669,6 +1151,104 @@ impl<T> Array2D<T> {
         .expect("Source Array2D should have compatible values for num_rows, num_columns, and enumerate_column_major")
     }
 
+    /// Changes every element in place by calling `mapper` on a mutable
+    /// reference to it. Each element is traversed in [row major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// array.map_in_place(|x| *x *= 10);
+    /// assert_eq!(array.as_rows(), vec![vec![10, 20, 30], vec![40, 50, 60]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn map_in_place<F>(&mut self, mapper: F)
+    where
+        S: StorageMut<T>,
+        F: FnMut(&mut T),
+    {
+        self.elements_row_major_iter_mut().for_each(mapper);
+    }
+
+    /// Changes every element in place by calling `mapper` on its index and a
+    /// mutable reference to it. Each element is traversed in [row major
+    /// order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(2, 3, 1);
+    /// array.map_in_place_with_index(|(row, column), x| *x += row * 10 + column);
+    /// assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![11, 12, 13]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn map_in_place_with_index<F>(&mut self, mut mapper: F)
+    where
+        S: StorageMut<T>,
+        F: FnMut((usize, usize), &mut T),
+    {
+        self.enumerate_row_major_mut()
+            .for_each(|(index, element)| mapper(index, element));
+    }
+
+    /// Consumes the [`Array2D`] and creates a new one by calling `mapper` on
+    /// each element by value. Each element is traversed in [row major order].
+    ///
+    /// Unlike [`map_row_major`], the elements are moved into `mapper` instead
+    /// of borrowed, and when `U` has the same size and alignment as `T` the
+    /// existing allocation may be reused for the new array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2], vec![3, 4]])?;
+    /// let strings = array.into_map(|x| x.to_string());
+    /// assert_eq!(strings.as_rows(), vec![vec!["1", "2"], vec!["3", "4"]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`map_row_major`]: struct.Array2D.html#method.map_row_major
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn into_map<F, U>(self, mapper: F) -> Array2D<U>
+    where
+        S: Into<Vec<T>>,
+        F: FnMut(T) -> U,
+    {
+        // The elements are put in row major order first so that they are
+        // moved out of the buffer in the order they are passed to `mapper`.
+        let array = Array2D {
+            array: self.array.into(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            layout: self.layout,
+            marker: PhantomData,
+        }
+        .into_layout(Layout::RowMajor);
+        // Collecting a mapped `vec::IntoIter` back into a `Vec` can reuse the
+        // source buffer whenever the layouts of `T` and `U` allow it.
+        Array2D {
+            array: array.array.into_iter().map(mapper).collect(),
+            num_rows: array.num_rows,
+            num_columns: array.num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
+        }
+    }
+
     /// The number of rows.
     pub fn num_rows(&self) -> usize {
         self.num_rows
@@ -695,22 +1275,26 @@ impl<T> Array2D<T> {
         self.num_rows
     }
 
-    /// Returns a reference to the element at the given `row` and `column` if the
-    /// index is in bounds (wrapped in [`Some`]). Returns [`None`] if the index
-    /// is out of bounds.
+    /// Returns a reference to the element at the given `index` if it is in
+    /// bounds (wrapped in [`Some`]). Returns [`None`] if the index is out of
+    /// bounds. The index can be any [`Index2D`], such as a `(row, column)`
+    /// tuple.
     ///
     /// # Examples
     ///
     /// ```
     /// # use array2d::{Array2D, Error};
     /// let array = Array2D::filled_with( 2, 3,42);
-    /// assert_eq!(array.get(0, 0), Some(&42));
-    /// assert_eq!(array.get(10, 10), None);
+    /// assert_eq!(array.get((0, 0)), Some(&42));
+    /// assert_eq!(array.get((10, 10)), None);
+    /// assert_eq!(array.get((-1_i32, 0)), None);
     /// ```
     ///
     /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
     /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
-    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+    /// [`Index2D`]: trait.Index2D.html
+    pub fn get<I: Index2D>(&self, index: I) -> Option<&T> {
+        let (row, column) = index.to_row_column();
         self.get_index(row, column).map(|index| &self.array[index])
     }
 
@@ -733,7 +1317,8 @@ impl<T> Array2D<T> {
     ///
     /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
     pub fn get_row_major(&self, index: usize) -> Option<&T> {
-        self.array.get(index)
+        let index = self.checked_storage_index_in(Layout::RowMajor, index)?;
+        Some(&self.array[index])
     }
 
     /// Returns a reference to the element at the given index in column major
@@ -755,14 +1340,83 @@ impl<T> Array2D<T> {
     ///
     /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
     pub fn get_column_major(&self, index: usize) -> Option<&T> {
-        let column = dbg!(dbg!(index) / self.num_rows);
-        let row = dbg!(index % self.num_rows);
-        self.get(row, column)
+        let index = self.checked_storage_index_in(Layout::ColumnMajor, index)?;
+        Some(&self.array[index])
+    }
+
+    /// Returns a reference to the element at the given signed `row` and
+    /// `column`, or [`None`] if either of them is negative or the index is
+    /// out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let array = Array2D::filled_with(2, 3, 42);
+    /// assert_eq!(array.get_signed(1, 2), Some(&42));
+    /// assert_eq!(array.get_signed(-1, 2), None);
+    /// assert_eq!(array.get_signed(1, 3), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_signed(&self, row: isize, column: isize) -> Option<&T> {
+        let index = self.step((0, 0), (row, column))?;
+        self.get(index)
+    }
+
+    /// Returns the index reached by moving from `(row, column)` by the signed
+    /// `(row_offset, column_offset)`, or [`None`] if it would be negative or
+    /// out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let array = Array2D::filled_with(2, 3, 42);
+    /// assert_eq!(array.step((1, 1), (-1, 1)), Some((0, 2)));
+    /// assert_eq!(array.step((1, 1), (-2, 0)), None);
+    /// assert_eq!(array.step((1, 1), (0, 2)), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn step(
+        &self,
+        (row, column): (usize, usize),
+        (row_offset, column_offset): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(row_offset)?;
+        let column = column.checked_add_signed(column_offset)?;
+        self.get_index(row, column).map(|_| (row, column))
+    }
+
+    /// Returns a reference to the element reached by moving from `(row,
+    /// column)` by the signed `(row_offset, column_offset)`, or [`None`] if
+    /// that index would be negative or out of bounds. See [`step`] for the
+    /// index itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(array.get_offset((1, 1), (-1, 1)), Some(&3));
+    /// assert_eq!(array.get_offset((0, 0), (0, -1)), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`step`]: struct.Array2D.html#method.step
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_offset(&self, index: (usize, usize), offset: (isize, isize)) -> Option<&T> {
+        let index = self.step(index, offset)?;
+        self.get(index)
     }
 
-    /// Returns a mutable reference to the element at the given `row` and
-    /// `column` if the index is in bounds (wrapped in [`Some`]). Returns
-    /// [`None`] if the index is out of bounds.
+    /// Returns a mutable reference to the element at the given `index` if it
+    /// is in bounds (wrapped in [`Some`]). Returns [`None`] if the index is
+    /// out of bounds. The index can be any [`Index2D`], such as a `(row,
+    /// column)` tuple.
     ///
     /// # Examples
     ///
@@ -770,19 +1424,24 @@ impl<T> Array2D<T> {
     /// # use array2d::{Array2D, Error};
     /// let mut array = Array2D::filled_with( 2, 3,42);
     ///
-    /// assert_eq!(array.get_mut(0, 0), Some(&mut 42));
-    /// assert_eq!(array.get_mut(10, 10), None);
+    /// assert_eq!(array.get_mut((0, 0)), Some(&mut 42));
+    /// assert_eq!(array.get_mut((10, 10)), None);
     ///
-    /// array.get_mut(0, 0).map(|x| *x = 100);
-    /// assert_eq!(array.get(0, 0), Some(&100));
+    /// array.get_mut((0, 0)).map(|x| *x = 100);
+    /// assert_eq!(array.get((0, 0)), Some(&100));
     ///
-    /// array.get_mut(10, 10).map(|x| *x = 200);
-    /// assert_eq!(array.get(10, 10), None);
+    /// array.get_mut((10, 10)).map(|x| *x = 200);
+    /// assert_eq!(array.get((10, 10)), None);
     /// ```
     ///
     /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
     /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
-    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+    /// [`Index2D`]: trait.Index2D.html
+    pub fn get_mut<I: Index2D>(&mut self, index: I) -> Option<&mut T>
+    where
+        S: StorageMut<T>,
+    {
+        let (row, column) = index.to_row_column();
         self.get_index(row, column)
             .map(move |index| &mut self.array[index])
     }
@@ -802,17 +1461,21 @@ impl<T> Array2D<T> {
     /// assert_eq!(array.get_mut_row_major(10), None);
     ///
     /// array.get_mut_row_major(3).map(|x| *x = 100);
-    /// assert_eq!(array.get(1, 0), Some(&100));
+    /// assert_eq!(array.get((1, 0)), Some(&100));
     ///
     /// array.get_mut_row_major(10).map(|x| *x = 200);
-    /// assert_eq!(array.get(10, 10), None);
+    /// assert_eq!(array.get((10, 10)), None);
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
-    pub fn get_mut_row_major(&mut self, index: usize) -> Option<&mut T> {
-        self.array.get_mut(index)
+    pub fn get_mut_row_major(&mut self, index: usize) -> Option<&mut T>
+    where
+        S: StorageMut<T>,
+    {
+        let index = self.checked_storage_index_in(Layout::RowMajor, index)?;
+        Some(&mut self.array[index])
     }
 
     /// Returns a mutable reference to the element at the given index in row
@@ -830,24 +1493,228 @@ impl<T> Array2D<T> {
     /// assert_eq!(array.get_mut_column_major(10), None);
     ///
     /// array.get_mut_column_major(4).map(|x| *x = 100);
-    /// assert_eq!(array.get(0, 2), Some(&100));
+    /// assert_eq!(array.get((0, 2)), Some(&100));
     ///
     /// array.get_mut_column_major(10).map(|x| *x = 200);
-    /// assert_eq!(array.get(10, 10), None);
+    /// assert_eq!(array.get((10, 10)), None);
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
-    pub fn get_mut_column_major(&mut self, index: usize) -> Option<&mut T> {
-        let column = index / self.num_rows;
-        let row = index % self.num_rows;
-        self.get_mut(row, column)
+    pub fn get_mut_column_major(&mut self, index: usize) -> Option<&mut T>
+    where
+        S: StorageMut<T>,
+    {
+        let index = self.checked_storage_index_in(Layout::ColumnMajor, index)?;
+        Some(&mut self.array[index])
+    }
+
+    /// Returns a mutable reference to the element at the given signed `row`
+    /// and `column`, or [`None`] if either of them is negative or the index
+    /// is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(2, 3, 42);
+    /// *array.get_mut_signed(1, 2).unwrap() = 100;
+    /// assert_eq!(array.get((1, 2)), Some(&100));
+    /// assert_eq!(array.get_mut_signed(0, -1), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut_signed(&mut self, row: isize, column: isize) -> Option<&mut T>
+    where
+        S: StorageMut<T>,
+    {
+        let index = self.step((0, 0), (row, column))?;
+        self.get_mut(index)
+    }
+
+    /// Returns a mutable reference to the element reached by moving from
+    /// `(row, column)` by the signed `(row_offset, column_offset)`, or
+    /// [`None`] if that index would be negative or out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(2, 3, 0);
+    /// *array.get_mut_offset((1, 1), (-1, 1)).unwrap() = 100;
+    /// assert_eq!(array.get((0, 2)), Some(&100));
+    /// assert_eq!(array.get_mut_offset((1, 1), (1, 0)), None);
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut_offset(
+        &mut self,
+        index: (usize, usize),
+        offset: (isize, isize),
+    ) -> Option<&mut T>
+    where
+        S: StorageMut<T>,
+    {
+        let index = self.step(index, offset)?;
+        self.get_mut(index)
+    }
+
+    /// Returns a reference to the element at the given `index`, without
+    /// checking that it is in bounds. The index can be any [`Index2D`], such
+    /// as a `(row, column)` tuple.
+    ///
+    /// This is useful in hot loops where the indices have already been
+    /// checked. For a safe alternative see [`get`].
+    ///
+    /// # Safety
+    ///
+    /// The index must be in bounds. Calling this method with an out-of-bounds
+    /// index is *[undefined behavior]* even if the resulting reference is not
+    /// used. In debug builds, this is checked with a [`debug_assert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let mut sum = 0;
+    /// for row in 0..array.num_rows() {
+    ///     for column in 0..array.num_columns() {
+    ///         // SAFETY: The loops stay within the dimensions of the array.
+    ///         sum += unsafe { array.get_unchecked((row, column)) };
+    ///     }
+    /// }
+    /// assert_eq!(sum, 21);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    /// [`get`]: struct.Array2D.html#method.get
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    /// [`debug_assert`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+    pub unsafe fn get_unchecked<I: Index2D>(&self, index: I) -> &T {
+        let (row, column) = index.to_row_column();
+        self.debug_assert_in_bounds(row, column);
+        self.array.get_unchecked(self.storage_index(row, column))
+    }
+
+    /// Returns a mutable reference to the element at the given `index`,
+    /// without checking that it is in bounds. The index can be any
+    /// [`Index2D`], such as a `(row, column)` tuple. For a safe alternative
+    /// see [`get_mut`].
+    ///
+    /// # Safety
+    ///
+    /// The index must be in bounds. Calling this method with an out-of-bounds
+    /// index is *[undefined behavior]* even if the resulting reference is not
+    /// used. In debug builds, this is checked with a [`debug_assert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(2, 3, 0);
+    /// // SAFETY: (1, 2) is within the 2 by 3 array.
+    /// unsafe { *array.get_unchecked_mut((1, 2)) = 100 };
+    /// assert_eq!(array[(1, 2)], 100);
+    /// ```
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    /// [`get_mut`]: struct.Array2D.html#method.get_mut
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    /// [`debug_assert`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+    pub unsafe fn get_unchecked_mut<I: Index2D>(&mut self, index: I) -> &mut T
+    where
+        S: StorageMut<T>,
+    {
+        let (row, column) = index.to_row_column();
+        self.debug_assert_in_bounds(row, column);
+        let index = self.storage_index(row, column);
+        self.array.get_unchecked_mut(index)
     }
 
-    /// Changes the element at given `row` and `column` to `element`. Returns
+    /// Returns a reference to the element at the given index in row major
+    /// order, without checking that it is in bounds. For a safe alternative
+    /// see [`get_row_major`].
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than [`num_elements`]. Calling this method with
+    /// an out-of-bounds index is *[undefined behavior]* even if the resulting
+    /// reference is not used. In debug builds, this is checked with a
+    /// [`debug_assert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// // SAFETY: The array has 6 elements.
+    /// assert_eq!(unsafe { array.get_unchecked_row_major(4) }, &5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`get_row_major`]: struct.Array2D.html#method.get_row_major
+    /// [`num_elements`]: struct.Array2D.html#method.num_elements
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    /// [`debug_assert`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+    pub unsafe fn get_unchecked_row_major(&self, index: usize) -> &T {
+        debug_assert!(
+            index < self.array.len(),
+            "Unchecked index {} out of bounds",
+            index
+        );
+        self.array
+            .get_unchecked(self.storage_index_in(Layout::RowMajor, index))
+    }
+
+    /// Returns a mutable reference to the element at the given index in row
+    /// major order, without checking that it is in bounds. For a safe
+    /// alternative see [`get_mut_row_major`].
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than [`num_elements`]. Calling this method with
+    /// an out-of-bounds index is *[undefined behavior]* even if the resulting
+    /// reference is not used. In debug builds, this is checked with a
+    /// [`debug_assert`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(2, 3, 0);
+    /// // SAFETY: The array has 6 elements.
+    /// unsafe { *array.get_unchecked_mut_row_major(3) = 100 };
+    /// assert_eq!(array[(1, 0)], 100);
+    /// ```
+    ///
+    /// [`get_mut_row_major`]: struct.Array2D.html#method.get_mut_row_major
+    /// [`num_elements`]: struct.Array2D.html#method.num_elements
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    /// [`debug_assert`]: https://doc.rust-lang.org/std/macro.debug_assert.html
+    pub unsafe fn get_unchecked_mut_row_major(&mut self, index: usize) -> &mut T
+    where
+        S: StorageMut<T>,
+    {
+        debug_assert!(
+            index < self.array.len(),
+            "Unchecked index {} out of bounds",
+            index
+        );
+        let index = self.storage_index_in(Layout::RowMajor, index);
+        self.array.get_unchecked_mut(index)
+    }
+
+    /// Changes the element at the given `index` to `element`. Returns
     /// [`Ok(())`] if the indices were in bounds and returns an [`Err`]
-    /// otherwise.
+    /// otherwise. The index can be any [`Index2D`], such as a `(row, column)`
+    /// tuple; negative indices are out of bounds.
     ///
     /// # Examples
     ///
@@ -855,24 +1722,43 @@ impl<T> Array2D<T> {
     /// # use array2d::{Array2D, Error};
     /// let mut array = Array2D::filled_with( 2, 3,42);
     ///
-    /// let result = array.set(0, 0, 100);
+    /// let result = array.set((0, 0), 100);
     /// assert_eq!(result, Ok(()));
-    /// assert_eq!(array.get(0, 0), Some(&100));
+    /// assert_eq!(array.get((0, 0)), Some(&100));
+    ///
+    /// let result = array.set((10, 20), 200);
+    /// assert_eq!(
+    ///     result,
+    ///     Err(Error::IndicesOutOfBounds {
+    ///         row: 10,
+    ///         column: 20,
+    ///         num_rows: 2,
+    ///         num_columns: 3
+    ///     })
+    /// );
     ///
-    /// let result = array.set(10, 20, 200);
-    /// assert_eq!(result, Err(Error::IndicesOutOfBounds(10, 20)));
+    /// let result = array.set((-1_i32, 0), 300);
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(Error::IndicesOutOfBounds { row: usize::MAX, .. })
+    /// ));
     /// ```
     ///
     /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
     /// [array2d::Error]: enum.Error.html
     /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
     /// [`array2d::Error`]: enum.Error.html
-    pub fn set(&mut self, row: usize, column: usize, element: T) -> Result<(), Error> {
-        self.get_mut(row, column)
+    /// [`Index2D`]: trait.Index2D.html
+    pub fn set<I: Index2D>(&mut self, index: I, element: T) -> Result<(), Error>
+    where
+        S: StorageMut<T>,
+    {
+        let (row, column) = index.to_row_column();
+        self.get_mut((row, column))
             .map(|location| {
                 *location = element;
             })
-            .ok_or(Error::IndicesOutOfBounds(row, column))
+            .ok_or_else(|| self.indices_out_of_bounds(row, column))
     }
 
     /// Changes the element at the given `index` to `element`, in row major
@@ -887,22 +1773,34 @@ impl<T> Array2D<T> {
     ///
     /// let result = array.set_row_major(4, 100);
     /// assert_eq!(result, Ok(()));
-    /// assert_eq!(array.get(1, 1), Some(&100));
+    /// assert_eq!(array.get((1, 1)), Some(&100));
     ///
     /// let result = array.set_row_major(10, 200);
-    /// assert_eq!(result, Err(Error::IndexOutOfBounds(10)));
+    /// assert_eq!(
+    ///     result,
+    ///     Err(Error::IndexOutOfBounds {
+    ///         index: 10,
+    ///         num_elements: 6
+    ///     })
+    /// );
     /// ```
     ///
     /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
     /// [array2d::Error]: enum.Error.html
     /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
     /// [`array2d::Error`]: enum.Error.html
-    pub fn set_row_major(&mut self, index: usize, element: T) -> Result<(), Error> {
+    pub fn set_row_major(&mut self, index: usize, element: T) -> Result<(), Error>
+    where
+        S: StorageMut<T>,
+    {
         self.get_mut_row_major(index)
             .map(|location| {
                 *location = element;
             })
-            .ok_or(Error::IndexOutOfBounds(index))
+            .ok_or_else(|| Error::IndexOutOfBounds {
+                index,
+                num_elements: self.num_elements(),
+            })
     }
 
     /// Changes the element at the given `index` to `element`, in column major
@@ -917,22 +1815,34 @@ impl<T> Array2D<T> {
     ///
     /// let result = array.set_column_major(4, 100);
     /// assert_eq!(result, Ok(()));
-    /// assert_eq!(array.get(0, 2), Some(&100));
+    /// assert_eq!(array.get((0, 2)), Some(&100));
     ///
     /// let result = array.set_column_major(10, 200);
-    /// assert_eq!(result, Err(Error::IndexOutOfBounds(10)));
+    /// assert_eq!(
+    ///     result,
+    ///     Err(Error::IndexOutOfBounds {
+    ///         index: 10,
+    ///         num_elements: 6
+    ///     })
+    /// );
     /// ```
     ///
     /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
     /// [array2d::Error]: enum.Error.html
     /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
     /// [`array2d::Error`]: enum.Error.html
-    pub fn set_column_major(&mut self, index: usize, element: T) -> Result<(), Error> {
+    pub fn set_column_major(&mut self, index: usize, element: T) -> Result<(), Error>
+    where
+        S: StorageMut<T>,
+    {
         self.get_mut_column_major(index)
             .map(|location| {
                 *location = element;
             })
-            .ok_or(Error::IndexOutOfBounds(index))
+            .ok_or_else(|| Error::IndexOutOfBounds {
+                index,
+                num_elements: self.num_elements(),
+            })
     }
 
     /// Returns an [`Iterator`] over references to all elements in [row major
@@ -954,8 +1864,51 @@ impl<T> Array2D<T> {
     ///
     /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
     /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
-    pub fn elements_row_major_iter(&self) -> impl DoubleEndedIterator<Item = &T> + Clone {
-        self.array.iter()
+    pub fn elements_row_major_iter(&self) -> ElementsRowMajorIter<'_, T> {
+        // SAFETY: The buffer holds exactly `num_rows * num_columns` elements.
+        unsafe {
+            ElementsRowMajorIter::new(
+                self.array.as_ptr(),
+                self.num_rows,
+                self.num_columns,
+                self.layout,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in
+    /// [row major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let mut array = Array2D::from_rows(&rows)?;
+    /// for (element, new_value) in array.elements_row_major_iter_mut().zip(10..) {
+    ///     *element = new_value;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![10, 11, 12], vec![13, 14, 15]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_row_major_iter_mut(&mut self) -> ElementsRowMajorIterMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        // SAFETY: The buffer holds exactly `num_rows * num_columns` elements.
+        unsafe {
+            ElementsRowMajorIterMut::new(
+                self.array.as_mut_ptr(),
+                self.num_rows,
+                self.num_columns,
+                self.layout,
+            )
+        }
     }
 
     /// Returns an [`Iterator`] over references to all elements in [column major
@@ -977,8 +1930,51 @@ impl<T> Array2D<T> {
     ///
     /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
     /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
-    pub fn elements_column_major_iter(&self) -> impl DoubleEndedIterator<Item = &T> + Clone {
-        self.indices_column_major().map(move |i| &self[i])
+    pub fn elements_column_major_iter(&self) -> ElementsColumnMajorIter<'_, T> {
+        // SAFETY: The buffer holds exactly `num_rows * num_columns` elements.
+        unsafe {
+            ElementsColumnMajorIter::new(
+                self.array.as_ptr(),
+                self.num_rows,
+                self.num_columns,
+                self.layout,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in
+    /// [column major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let mut array = Array2D::from_rows(&rows)?;
+    /// for (element, new_value) in array.elements_column_major_iter_mut().zip(10..) {
+    ///     *element = new_value;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![10, 12, 14], vec![11, 13, 15]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_column_major_iter_mut(&mut self) -> ElementsColumnMajorIterMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        // SAFETY: The buffer holds exactly `num_rows * num_columns` elements.
+        unsafe {
+            ElementsColumnMajorIterMut::new(
+                self.array.as_mut_ptr(),
+                self.num_rows,
+                self.num_columns,
+                self.layout,
+            )
+        }
     }
 
     /// Returns an [`Iterator`] over references to all elements in the given
@@ -1001,15 +1997,54 @@ impl<T> Array2D<T> {
     /// ```
     ///
     /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
-    pub fn row_iter(
-        &self,
-        row_index: usize,
-    ) -> Result<impl DoubleEndedIterator<Item = &T> + Clone, Error> {
-        let start = self
-            .get_index(row_index, 0)
-            .ok_or(Error::IndicesOutOfBounds(row_index, 0))?;
-        let end = start + self.row_len();
-        Ok(self.array[start..end].iter())
+    pub fn row_iter(&self, row_index: usize) -> Result<RowIter<'_, T>, Error> {
+        let start = self.row_start(row_index)?;
+        let (_, column_stride) = self.strides();
+        // SAFETY: The row starts in bounds and its elements are one column
+        // apart.
+        Ok(unsafe {
+            RowIter::new(
+                self.array.as_ptr().add(start),
+                self.row_len(),
+                column_stride,
+            )
+        })
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in the
+    /// given row. Returns an error if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let mut array = Array2D::from_rows(&rows)?;
+    /// for element in array.row_iter_mut(1)? {
+    ///     *element *= 10;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![40, 50, 60]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter_mut(&mut self, row_index: usize) -> Result<RowIterMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        let start = self.row_start(row_index)?;
+        let (_, column_stride) = self.strides();
+        // SAFETY: The row starts in bounds and its elements are one column
+        // apart.
+        Ok(unsafe {
+            RowIterMut::new(
+                self.array.as_mut_ptr().add(start),
+                self.row_len(),
+                column_stride,
+            )
+        })
     }
 
     /// Returns an [`Iterator`] over references to all elements in the given
@@ -1031,14 +2066,54 @@ impl<T> Array2D<T> {
     /// ```
     ///
     /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
-    pub fn column_iter(
-        &self,
-        column_index: usize,
-    ) -> Result<impl DoubleEndedIterator<Item = &T> + Clone, Error> {
-        if column_index >= self.num_columns {
-            return Err(Error::IndicesOutOfBounds(0, column_index));
-        }
-        Ok((0..self.column_len()).map(move |row_index| &self[(row_index, column_index)]))
+    pub fn column_iter(&self, column_index: usize) -> Result<ColumnIter<'_, T>, Error> {
+        let start = self.column_start(column_index)?;
+        let (row_stride, _) = self.strides();
+        // SAFETY: The column starts in bounds and its elements are one row
+        // apart.
+        Ok(unsafe {
+            ColumnIter::new(
+                self.array.as_ptr().add(start),
+                self.column_len(),
+                row_stride,
+            )
+        })
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements in the
+    /// given column. Returns an error if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let mut array = Array2D::from_rows(&rows)?;
+    /// for element in array.column_iter_mut(1)? {
+    ///     *element *= 10;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![1, 20, 3], vec![4, 50, 6]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn column_iter_mut(&mut self, column_index: usize) -> Result<ColumnIterMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        let start = self.column_start(column_index)?;
+        let (row_stride, _) = self.strides();
+        // SAFETY: The column starts in bounds and its elements are one row
+        // apart.
+        Ok(unsafe {
+            ColumnIterMut::new(
+                self.array.as_mut_ptr().add(start),
+                self.column_len(),
+                row_stride,
+            )
+        })
     }
 
     /// Returns an [`Iterator`] over all rows. Each [`Item`] is itself another
@@ -1079,13 +2154,57 @@ impl<T> Array2D<T> {
     ///
     /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
     /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
-    pub fn rows_iter(
-        &self,
-    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + Clone> + Clone {
-        (0..self.num_rows()).map(move |row_index| {
-            self.row_iter(row_index)
-                .expect("rows_iter should never fail")
-        })
+    pub fn rows_iter(&self) -> RowsIter<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+        // SAFETY: The strides describe exactly the elements of the buffer.
+        unsafe {
+            RowsIter::new(
+                self.array.as_ptr(),
+                self.num_rows,
+                row_stride,
+                self.row_len(),
+                column_stride,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over all rows. Each [`Item`] is itself another
+    /// [`Iterator`] over mutable references to the elements in that row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let mut array = Array2D::from_rows(&rows)?;
+    /// for (row_index, row_iter) in array.rows_iter_mut().enumerate() {
+    ///     for element in row_iter {
+    ///         *element += row_index * 10;
+    ///     }
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![14, 15, 16]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    pub fn rows_iter_mut(&mut self) -> RowsIterMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        let (row_stride, column_stride) = self.strides();
+        // SAFETY: The strides describe exactly the elements of the buffer.
+        unsafe {
+            RowsIterMut::new(
+                self.array.as_mut_ptr(),
+                self.num_rows,
+                row_stride,
+                self.row_len(),
+                column_stride,
+            )
+        }
     }
 
     /// Returns an [`Iterator`] over all columns. Each [`Item`] is itself
@@ -1129,13 +2248,58 @@ impl<T> Array2D<T> {
     ///
     /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
     /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
-    pub fn columns_iter(
-        &self,
-    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + Clone> + Clone {
-        (0..self.num_columns).map(move |column_index| {
-            self.column_iter(column_index)
-                .expect("columns_iter should never fail")
-        })
+    pub fn columns_iter(&self) -> ColumnsIter<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+        // SAFETY: The strides describe exactly the elements of the buffer.
+        unsafe {
+            ColumnsIter::new(
+                self.array.as_ptr(),
+                self.num_columns,
+                column_stride,
+                self.column_len(),
+                row_stride,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over all columns. Each [`Item`] is itself
+    /// another [`Iterator`] over mutable references to the elements in that
+    /// column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let mut array = Array2D::from_rows(&rows)?;
+    /// for (column_index, column_iter) in array.columns_iter_mut().enumerate() {
+    ///     for element in column_iter {
+    ///         *element += column_index * 10;
+    ///     }
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![1, 12, 23], vec![4, 15, 26]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    pub fn columns_iter_mut(&mut self) -> ColumnsIterMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        let (row_stride, column_stride) = self.strides();
+        // SAFETY: The strides describe exactly the elements of the buffer.
+        unsafe {
+            ColumnsIterMut::new(
+                self.array.as_mut_ptr(),
+                self.num_columns,
+                column_stride,
+                self.column_len(),
+                row_stride,
+            )
+        }
     }
 
     /// Collects the [`Array2D`] into a [`Vec`] of rows, each of which contains
@@ -1259,8 +2423,8 @@ impl<T> Array2D<T> {
     /// ```
     ///
     /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
-    pub fn indices_row_major(&self) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
-        indices_row_major(self.num_rows, self.num_columns)
+    pub fn indices_row_major(&self) -> IndicesRowMajor {
+        IndicesRowMajor::new(self.num_rows, self.num_columns)
     }
 
     /// Returns the indices of the array in column major order. Each index is a tuple of [`usize`].
@@ -1282,8 +2446,8 @@ impl<T> Array2D<T> {
     /// ```
     ///
     /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
-    pub fn indices_column_major(&self) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
-        indices_column_major(self.num_rows, self.num_columns)
+    pub fn indices_column_major(&self) -> IndicesColumnMajor {
+        IndicesColumnMajor::new(self.num_rows, self.num_columns)
     }
 
     /// Iterate through the array in row major order along with the corresponding indices. Each
@@ -1313,10 +2477,49 @@ impl<T> Array2D<T> {
     /// ```
     ///
     /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
-    pub fn enumerate_row_major(
-        &self,
-    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + Clone {
-        self.indices_row_major().map(move |i| (i, &self[i]))
+    pub fn enumerate_row_major(&self) -> EnumerateRowMajor<'_, T> {
+        // SAFETY: The buffer holds exactly `num_rows * num_columns` elements.
+        unsafe {
+            EnumerateRowMajor::new(
+                self.array.as_ptr(),
+                self.num_rows,
+                self.num_columns,
+                self.layout,
+            )
+        }
+    }
+
+    /// Iterate through the array in row major order along with the corresponding indices, with
+    /// mutable references to the elements. Each index is a tuple of [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(2, 3, 0);
+    /// for ((row, column), element) in array.enumerate_row_major_mut() {
+    ///     *element = row * 10 + column;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![0, 1, 2], vec![10, 11, 12]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    pub fn enumerate_row_major_mut(&mut self) -> EnumerateRowMajorMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        // SAFETY: The buffer holds exactly `num_rows * num_columns` elements.
+        unsafe {
+            EnumerateRowMajorMut::new(
+                self.array.as_mut_ptr(),
+                self.num_rows,
+                self.num_columns,
+                self.layout,
+            )
+        }
     }
 
     /// Iterate through the array in column major order along with the corresponding indices. Each
@@ -1346,17 +2549,24 @@ impl<T> Array2D<T> {
     /// ```
     ///
     /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
-    pub fn enumerate_column_major(
-        &self,
-    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + Clone {
-        self.indices_column_major().map(move |i| (i, &self[i]))
+    pub fn enumerate_column_major(&self) -> EnumerateColumnMajor<'_, T> {
+        // SAFETY: The buffer holds exactly `num_rows * num_columns` elements.
+        unsafe {
+            EnumerateColumnMajor::new(
+                self.array.as_ptr(),
+                self.num_rows,
+                self.num_columns,
+                self.layout,
+            )
+        }
     }
 
     /// Swaps two elements in the [`Array2D`]. Returns an empty [`Ok`] value if both indices are in bounds and the
     /// values were successfully swapped (if the indices were different). If either index was out of bounds, an
-    /// [`Err`] is returned with the out-of-bounds index.
+    /// [`Err`] is returned with the out-of-bounds index. The indices can be any [`Index2D`], such as `(row, column)`
+    /// tuples.
     ///
-    /// If `row1` equals to `row2` and `column1` equals to `column2`, it's guaranteed that elements won't change value.
+    /// If `index1` equals to `index2`, it's guaranteed that elements won't change value.
     ///
     /// # Examples
     ///
@@ -1371,34 +2581,164 @@ impl<T> Array2D<T> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn swap(
-        &mut self,
-        (row1, column1): (usize, usize),
-        (row2, column2): (usize, usize),
-    ) -> Result<(), Error> {
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    pub fn swap<I: Index2D, J: Index2D>(&mut self, index1: I, index2: J) -> Result<(), Error>
+    where
+        S: StorageMut<T>,
+    {
+        let (row1, column1) = index1.to_row_column();
+        let (row2, column2) = index2.to_row_column();
         let index1 = self
             .get_index(row1, column1)
-            .ok_or(Error::IndicesOutOfBounds(row1, column1))?;
+            .ok_or_else(|| self.indices_out_of_bounds(row1, column1))?;
         let index2 = self
             .get_index(row2, column2)
-            .ok_or(Error::IndicesOutOfBounds(row2, column2))?;
+            .ok_or_else(|| self.indices_out_of_bounds(row2, column2))?;
         self.array.swap(index1, index2);
         Ok(())
     }
 
+    /// Returns mutable references to several elements at once. Each index is a
+    /// tuple of `(row, column)`, and the references are returned in the same
+    /// order as the indices.
+    ///
+    /// Returns an [`Err`] if any of the indices are out of bounds, or if the
+    /// same indices appear more than once (which would create two mutable
+    /// references to the same element).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec!['X', ' ', ' '], vec![' ', ' ', ' ']];
+    /// let mut board = Array2D::from_rows(&rows)?;
+    /// let [from, to] = board.get_many_mut([(0, 0), (1, 2)])?;
+    /// std::mem::swap(from, to);
+    /// assert_eq!(board.as_rows(), vec![vec![' ', ' ', ' '], vec![' ', ' ', 'X']]);
+    ///
+    /// let result = board.get_many_mut([(0, 1), (0, 1)]);
+    /// assert_eq!(result.err(), Some(Error::DuplicateIndices { row: 0, column: 1 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn get_many_mut<const N: usize>(
+        &mut self,
+        indices: [(usize, usize); N],
+    ) -> Result<[&mut T; N], Error>
+    where
+        S: StorageMut<T>,
+    {
+        let mut flat_indices = [0; N];
+        for (position, &(row, column)) in indices.iter().enumerate() {
+            let index = self
+                .get_index(row, column)
+                .ok_or_else(|| self.indices_out_of_bounds(row, column))?;
+            if flat_indices[..position].contains(&index) {
+                return Err(Error::DuplicateIndices { row, column });
+            }
+            flat_indices[position] = index;
+        }
+        let ptr = self.array.as_mut_ptr();
+        // SAFETY: The indices were all checked to be in bounds and distinct,
+        // so the mutable references never alias.
+        Ok(flat_indices.map(|index| unsafe { &mut *ptr.add(index) }))
+    }
+
+    fn debug_assert_in_bounds(&self, row: usize, column: usize) {
+        debug_assert!(
+            row < self.num_rows && column < self.num_columns,
+            "Unchecked indices {}, {} out of bounds",
+            row,
+            column
+        );
+    }
+
+    /// The position in the buffer of the first element of the row. An empty
+    /// row starts at the beginning of the buffer, so that it is never offset
+    /// past the end.
+    fn row_start(&self, row_index: usize) -> Result<usize, Error> {
+        if row_index < self.num_rows {
+            Ok(self.get_index(row_index, 0).unwrap_or(0))
+        } else {
+            Err(Error::RowOutOfBounds {
+                row: row_index,
+                num_rows: self.num_rows,
+            })
+        }
+    }
+
+    /// The position in the buffer of the first element of the column. An
+    /// empty column starts at the beginning of the buffer, so that it is
+    /// never offset past the end.
+    fn column_start(&self, column_index: usize) -> Result<usize, Error> {
+        if column_index < self.num_columns {
+            Ok(self.get_index(0, column_index).unwrap_or(0))
+        } else {
+            Err(Error::ColumnOutOfBounds {
+                column: column_index,
+                num_columns: self.num_columns,
+            })
+        }
+    }
+
+    /// The distances in the buffer between neighboring rows and between
+    /// neighboring columns, as `(row_stride, column_stride)`.
+    pub(crate) fn strides(&self) -> (usize, usize) {
+        self.layout.strides(self.num_rows, self.num_columns)
+    }
+
+    pub(crate) fn indices_out_of_bounds(&self, row: usize, column: usize) -> Error {
+        Error::IndicesOutOfBounds {
+            row,
+            column,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+        }
+    }
+
     fn get_index(&self, row: usize, column: usize) -> Option<usize> {
         if row < self.num_rows && column < self.num_columns {
-            Some(row * self.row_len() + column)
+            Some(self.storage_index(row, column))
         } else {
             None
         }
     }
 }
 
-impl<T> Index<(usize, usize)> for Array2D<T> {
+impl<T, S: Clone> Clone for Array2D<T, S> {
+    fn clone(&self) -> Self {
+        Array2D {
+            array: self.array.clone(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            layout: self.layout,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, S: Copy> Copy for Array2D<T, S> {}
+
+impl<T, S: Debug> Debug for Array2D<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Array2D")
+            .field("array", &self.array)
+            .field("num_rows", &self.num_rows)
+            .field("num_columns", &self.num_columns)
+            .field("layout", &self.layout)
+            .finish()
+    }
+}
+
+impl<T, S: Storage<T>, I: Index2D> Index<I> for Array2D<T, S> {
     type Output = T;
 
-    /// Returns the element at the given indices, given as `(row, column)`.
+    /// Returns the element at the given index, given as any [`Index2D`] such
+    /// as `(row, column)`.
     ///
     /// # Examples
     ///
@@ -1417,15 +2757,18 @@ impl<T> Index<(usize, usize)> for Array2D<T> {
     /// let array = Array2D::filled_with( 2, 3,42);
     /// let element = array[(10, 10)];
     /// ```
-    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
-        self.get(row, column)
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    fn index(&self, index: I) -> &Self::Output {
+        let (row, column) = index.to_row_column();
+        self.get((row, column))
             .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
     }
 }
 
-impl<T> IndexMut<(usize, usize)> for Array2D<T> {
-    /// Returns a mutable version of the element at the given indices, given as
-    /// `(row, column)`.
+impl<T, S: StorageMut<T>, I: Index2D> IndexMut<I> for Array2D<T, S> {
+    /// Returns a mutable version of the element at the given index, given as
+    /// any [`Index2D`] such as `(row, column)`.
     ///
     /// # Examples
     ///
@@ -1445,26 +2788,65 @@ impl<T> IndexMut<(usize, usize)> for Array2D<T> {
     /// let mut array = Array2D::filled_with( 2, 3,42);
     /// array[(10, 10)] = 7;
     /// ```
-    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
-        self.get_mut(row, column)
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let (row, column) = index.to_row_column();
+        self.get_mut((row, column))
             .unwrap_or_else(|| panic!("Index mut indices {}, {} out of bounds", row, column))
     }
 }
 
+impl<T, U, S, R> PartialEq<Array2D<U, R>> for Array2D<T, S>
+where
+    T: PartialEq<U>,
+    S: Storage<T>,
+    R: Storage<U>,
+{
+    fn eq(&self, other: &Array2D<U, R>) -> bool {
+        if (self.num_rows, self.num_columns) != (other.num_rows, other.num_columns) {
+            return false;
+        }
+        if self.layout == other.layout {
+            *self.array == *other.array
+        } else {
+            self.elements_row_major_iter()
+                .eq(other.elements_row_major_iter())
+        }
+    }
+}
+
+impl<T: Eq, S: Storage<T>> Eq for Array2D<T, S> {}
+
+impl<T: Hash, S: Storage<T>> Hash for Array2D<T, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hashed in row major order so that equal arrays with different
+        // layouts have equal hashes.
+        self.num_rows.hash(state);
+        self.num_columns.hash(state);
+        self.elements_row_major_iter()
+            .for_each(|element| element.hash(state));
+    }
+}
+
+/// The number of elements of an array with the given dimensions, or [`None`]
+/// if the number does not fit in a [`usize`], so that no array can claim more
+/// elements than its buffer holds.
+///
+/// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+/// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+pub(crate) fn total_len(num_rows: usize, num_columns: usize) -> Option<usize> {
+    num_rows.checked_mul(num_columns)
+}
+
 fn flatten<T: Clone>(nested: &[Vec<T>]) -> Vec<T> {
     nested.iter().flat_map(|row| row.clone()).collect()
 }
 
-fn indices_row_major(
-    num_rows: usize,
-    num_columns: usize,
-) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
-    (0..num_rows).flat_map(move |row| (0..num_columns).map(move |column| (row, column)))
+fn indices_row_major(num_rows: usize, num_columns: usize) -> IndicesRowMajor {
+    IndicesRowMajor::new(num_rows, num_columns)
 }
 
-fn indices_column_major(
-    num_rows: usize,
-    num_columns: usize,
-) -> impl DoubleEndedIterator<Item = (usize, usize)> + Clone {
-    (0..num_columns).flat_map(move |column| (0..num_rows).map(move |row| (row, column)))
+fn indices_column_major(num_rows: usize, num_columns: usize) -> IndicesColumnMajor {
+    IndicesColumnMajor::new(num_rows, num_columns)
 }
@@ -0,0 +1,731 @@
+//! Iterators over the neighbors of an element of an [`Array2D`].
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::wrapping::wrap;
+use crate::{Array2D, Error, Layout, Storage, StorageMut};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::slice;
+
+/// The offsets of the orthogonal neighbors, in row major order.
+const NEIGHBORS4: &[(isize, isize)] = &[(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The offsets of the orthogonal and diagonal neighbors, in row major order.
+const NEIGHBORS8: &[(isize, isize)] = &[
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns an [`Iterator`] over the up to four orthogonal neighbors of the
+    /// element at the given index, skipping those that fall outside the
+    /// array. Each [`Item`] is a tuple of the neighbor's index and a reference
+    /// to it. The neighbors are produced in [row major order], i.e. above,
+    /// left, right, below. Returns an error if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let neighbors = array.neighbors4((0, 1))?.collect::<Vec<_>>();
+    /// assert_eq!(neighbors, vec![((0, 0), &1), ((0, 2), &3), ((1, 1), &5)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn neighbors4(&self, index: (usize, usize)) -> Result<Neighbors<'_, T>, Error> {
+        self.neighbors_with(index, NEIGHBORS4)
+    }
+
+    /// Returns an [`Iterator`] over the up to eight orthogonal and diagonal
+    /// neighbors of the element at the given index, skipping those that fall
+    /// outside the array. Each [`Item`] is a tuple of the neighbor's index and
+    /// a reference to it. The neighbors are produced in [row major order].
+    /// Returns an error if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let neighbors = array.neighbors8((1, 0))?.map(|(_, &x)| x).collect::<Vec<_>>();
+    /// assert_eq!(neighbors, vec![1, 2, 5]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn neighbors8(&self, index: (usize, usize)) -> Result<Neighbors<'_, T>, Error> {
+        self.neighbors_with(index, NEIGHBORS8)
+    }
+
+    /// Returns an [`Iterator`] over the elements at the given `(row, column)`
+    /// offsets from the element at the given index, skipping those that fall
+    /// outside the array. Each [`Item`] is a tuple of the neighbor's index and
+    /// a reference to it. The neighbors are produced in the order of
+    /// `offsets`. Returns an error if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_iter_row_major(4, 4, 0..)?;
+    /// let knight_moves = [(-2, -1), (-2, 1), (-1, 2), (1, 2), (2, 1), (2, -1), (1, -2), (-1, -2)];
+    /// let indices = array
+    ///     .neighbors_with((0, 0), &knight_moves)?
+    ///     .map(|(index, _)| index)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(indices, vec![(1, 2), (2, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    pub fn neighbors_with<'a>(
+        &'a self,
+        index: (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> Result<Neighbors<'a, T>, Error> {
+        Ok(Neighbors {
+            indices: self.neighbors_with_indices(index, offsets)?,
+            array: self.borrowed(),
+        })
+    }
+
+    /// Returns an [`Iterator`] over the up to four orthogonal neighbors of the
+    /// element at the given index, with mutable references. See
+    /// [`neighbors4`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(3, 3, 0);
+    /// for (_, element) in array.neighbors4_mut((1, 1))? {
+    ///     *element = 1;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![0, 1, 0], vec![1, 0, 1], vec![0, 1, 0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors4`]: struct.Array2D.html#method.neighbors4
+    pub fn neighbors4_mut(&mut self, index: (usize, usize)) -> Result<NeighborsMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        self.neighbors_with_mut(index, NEIGHBORS4)
+    }
+
+    /// Returns an [`Iterator`] over the up to eight orthogonal and diagonal
+    /// neighbors of the element at the given index, with mutable references.
+    /// See [`neighbors8`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(2, 3, 0);
+    /// for (_, element) in array.neighbors8_mut((0, 0))? {
+    ///     *element += 1;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![0, 1, 0], vec![1, 1, 0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors8`]: struct.Array2D.html#method.neighbors8
+    pub fn neighbors8_mut(&mut self, index: (usize, usize)) -> Result<NeighborsMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        self.neighbors_with_mut(index, NEIGHBORS8)
+    }
+
+    /// Returns an [`Iterator`] over the elements at the given offsets from the
+    /// element at the given index, with mutable references. See
+    /// [`neighbors_with`]. Returns an error if the index is out of bounds, or
+    /// if an offset that stays in bounds is given more than once, since that
+    /// would produce two mutable references to the same element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(1, 4, 0);
+    /// for (_, element) in array.neighbors_with_mut((0, 0), &[(0, 1), (0, 3)])? {
+    ///     *element = 1;
+    /// }
+    /// assert_eq!(array.as_row_major(), vec![0, 1, 0, 1]);
+    ///
+    /// let result = array.neighbors_with_mut((0, 0), &[(0, 1), (0, 1)]);
+    /// assert_eq!(result.err(), Some(Error::DuplicateIndices { row: 0, column: 1 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors_with`]: struct.Array2D.html#method.neighbors_with
+    pub fn neighbors_with_mut<'a>(
+        &'a mut self,
+        index: (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> Result<NeighborsMut<'a, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        let indices = self.neighbor_indices(index, offsets, false)?;
+        self.neighbors_mut(indices)
+    }
+
+    /// Returns an [`Iterator`] over the indices of the up to four orthogonal
+    /// neighbors of the given index. See [`neighbors4`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::filled_with(3, 3, 0);
+    /// let indices = array.neighbors4_indices((2, 2))?.collect::<Vec<_>>();
+    /// assert_eq!(indices, vec![(1, 2), (2, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors4`]: struct.Array2D.html#method.neighbors4
+    pub fn neighbors4_indices(
+        &self,
+        index: (usize, usize),
+    ) -> Result<NeighborIndices<'static>, Error> {
+        self.neighbors_with_indices(index, NEIGHBORS4)
+    }
+
+    /// Returns an [`Iterator`] over the indices of the up to eight orthogonal
+    /// and diagonal neighbors of the given index. See [`neighbors8`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::filled_with(3, 3, 0);
+    /// assert_eq!(array.neighbors8_indices((1, 1))?.count(), 8);
+    /// assert_eq!(array.neighbors8_indices((0, 1))?.count(), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors8`]: struct.Array2D.html#method.neighbors8
+    pub fn neighbors8_indices(
+        &self,
+        index: (usize, usize),
+    ) -> Result<NeighborIndices<'static>, Error> {
+        self.neighbors_with_indices(index, NEIGHBORS8)
+    }
+
+    /// Returns an [`Iterator`] over the indices at the given offsets from the
+    /// given index. See [`neighbors_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::filled_with(3, 3, 0);
+    /// let indices = array
+    ///     .neighbors_with_indices((1, 1), &[(-1, -1), (2, 0), (1, 1)])?
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(indices, vec![(0, 0), (2, 2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors_with`]: struct.Array2D.html#method.neighbors_with
+    pub fn neighbors_with_indices<'o>(
+        &self,
+        index: (usize, usize),
+        offsets: &'o [(isize, isize)],
+    ) -> Result<NeighborIndices<'o>, Error> {
+        self.neighbor_indices(index, offsets, false)
+    }
+
+    /// Returns an [`Iterator`] over the four orthogonal neighbors of the
+    /// element at the given index, wrapping around the edges of the array
+    /// instead of skipping the neighbors outside it. See [`neighbors4`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let neighbors = array.neighbors4_wrapping((0, 0))?.collect::<Vec<_>>();
+    /// assert_eq!(neighbors, vec![((1, 0), &4), ((0, 2), &3), ((0, 1), &2), ((1, 0), &4)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors4`]: struct.Array2D.html#method.neighbors4
+    pub fn neighbors4_wrapping(&self, index: (usize, usize)) -> Result<Neighbors<'_, T>, Error> {
+        self.neighbors_with_wrapping(index, NEIGHBORS4)
+    }
+
+    /// Returns an [`Iterator`] over the eight orthogonal and diagonal
+    /// neighbors of the element at the given index, wrapping around the
+    /// edges of the array instead of skipping the neighbors outside it. See
+    /// [`neighbors8`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_iter_row_major(3, 3, 0..)?;
+    /// let total = array.neighbors8_wrapping((0, 0))?.map(|(_, &x)| x).sum::<i32>();
+    /// assert_eq!(total, 8 + 6 + 7 + 2 + 1 + 5 + 3 + 4);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors8`]: struct.Array2D.html#method.neighbors8
+    pub fn neighbors8_wrapping(&self, index: (usize, usize)) -> Result<Neighbors<'_, T>, Error> {
+        self.neighbors_with_wrapping(index, NEIGHBORS8)
+    }
+
+    /// Returns an [`Iterator`] over the elements at the given offsets from the
+    /// element at the given index, wrapping around the edges of the array
+    /// instead of skipping the elements outside it. See [`neighbors_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_iter_row_major(1, 5, 0..)?;
+    /// let far = array.neighbors_with_wrapping((0, 1), &[(0, -3), (0, 7)])?;
+    /// assert_eq!(far.collect::<Vec<_>>(), vec![((0, 3), &3), ((0, 3), &3)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors_with`]: struct.Array2D.html#method.neighbors_with
+    pub fn neighbors_with_wrapping<'a>(
+        &'a self,
+        index: (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> Result<Neighbors<'a, T>, Error> {
+        Ok(Neighbors {
+            indices: self.neighbors_with_wrapping_indices(index, offsets)?,
+            array: self.borrowed(),
+        })
+    }
+
+    /// Returns an [`Iterator`] over the four orthogonal neighbors of the
+    /// element at the given index, wrapping around the edges of the array,
+    /// with mutable references. See [`neighbors4_wrapping`]. Returns an error
+    /// if the array is so small that two of the neighbors are the same
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(3, 3, 0);
+    /// for (_, element) in array.neighbors4_wrapping_mut((0, 0))? {
+    ///     *element = 1;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![0, 1, 1], vec![1, 0, 0], vec![1, 0, 0]]);
+    ///
+    /// let mut small = Array2D::filled_with(2, 3, 0);
+    /// let result = small.neighbors4_wrapping_mut((0, 0));
+    /// assert_eq!(result.err(), Some(Error::DuplicateIndices { row: 1, column: 0 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors4_wrapping`]: struct.Array2D.html#method.neighbors4_wrapping
+    pub fn neighbors4_wrapping_mut(
+        &mut self,
+        index: (usize, usize),
+    ) -> Result<NeighborsMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        self.neighbors_with_wrapping_mut(index, NEIGHBORS4)
+    }
+
+    /// Returns an [`Iterator`] over the eight orthogonal and diagonal
+    /// neighbors of the element at the given index, wrapping around the edges
+    /// of the array, with mutable references. See [`neighbors8_wrapping`].
+    /// Returns an error if the array is so small that two of the neighbors
+    /// are the same element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(3, 3, 0);
+    /// for (_, element) in array.neighbors8_wrapping_mut((2, 2))? {
+    ///     *element += 1;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![1, 1, 1], vec![1, 1, 1], vec![1, 1, 0]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors8_wrapping`]: struct.Array2D.html#method.neighbors8_wrapping
+    pub fn neighbors8_wrapping_mut(
+        &mut self,
+        index: (usize, usize),
+    ) -> Result<NeighborsMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        self.neighbors_with_wrapping_mut(index, NEIGHBORS8)
+    }
+
+    /// Returns an [`Iterator`] over the elements at the given offsets from the
+    /// element at the given index, wrapping around the edges of the array,
+    /// with mutable references. See [`neighbors_with_wrapping`]. Returns an
+    /// error if the index is out of bounds, or if two offsets reach the same
+    /// element, since that would produce two mutable references to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(1, 4, 0);
+    /// for (_, element) in array.neighbors_with_wrapping_mut((0, 0), &[(0, -1), (0, 5)])? {
+    ///     *element = 1;
+    /// }
+    /// assert_eq!(array.as_row_major(), vec![0, 1, 0, 1]);
+    ///
+    /// let result = array.neighbors_with_wrapping_mut((0, 0), &[(0, -1), (0, 3)]);
+    /// assert_eq!(result.err(), Some(Error::DuplicateIndices { row: 0, column: 3 }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors_with_wrapping`]: struct.Array2D.html#method.neighbors_with_wrapping
+    pub fn neighbors_with_wrapping_mut<'a>(
+        &'a mut self,
+        index: (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> Result<NeighborsMut<'a, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        let indices = self.neighbor_indices(index, offsets, true)?;
+        self.neighbors_mut(indices)
+    }
+
+    /// Returns an [`Iterator`] over the indices of the four orthogonal
+    /// neighbors of the given index, wrapping around the edges of the array.
+    /// See [`neighbors4_wrapping`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::filled_with(3, 3, 0);
+    /// let indices = array.neighbors4_wrapping_indices((2, 2))?.collect::<Vec<_>>();
+    /// assert_eq!(indices, vec![(1, 2), (2, 1), (2, 0), (0, 2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors4_wrapping`]: struct.Array2D.html#method.neighbors4_wrapping
+    pub fn neighbors4_wrapping_indices(
+        &self,
+        index: (usize, usize),
+    ) -> Result<NeighborIndices<'static>, Error> {
+        self.neighbors_with_wrapping_indices(index, NEIGHBORS4)
+    }
+
+    /// Returns an [`Iterator`] over the indices of the eight orthogonal and
+    /// diagonal neighbors of the given index, wrapping around the edges of
+    /// the array. See [`neighbors8_wrapping`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::filled_with(3, 3, 0);
+    /// assert_eq!(array.neighbors8_wrapping_indices((0, 1))?.count(), 8);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors8_wrapping`]: struct.Array2D.html#method.neighbors8_wrapping
+    pub fn neighbors8_wrapping_indices(
+        &self,
+        index: (usize, usize),
+    ) -> Result<NeighborIndices<'static>, Error> {
+        self.neighbors_with_wrapping_indices(index, NEIGHBORS8)
+    }
+
+    /// Returns an [`Iterator`] over the indices at the given offsets from the
+    /// given index, wrapping around the edges of the array. See
+    /// [`neighbors_with_wrapping`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::filled_with(3, 3, 0);
+    /// let indices = array
+    ///     .neighbors_with_wrapping_indices((1, 1), &[(-1, -1), (2, 0), (-4, 4)])?
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(indices, vec![(0, 0), (0, 1), (0, 2)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`neighbors_with_wrapping`]: struct.Array2D.html#method.neighbors_with_wrapping
+    pub fn neighbors_with_wrapping_indices<'o>(
+        &self,
+        index: (usize, usize),
+        offsets: &'o [(isize, isize)],
+    ) -> Result<NeighborIndices<'o>, Error> {
+        self.neighbor_indices(index, offsets, true)
+    }
+
+    fn neighbor_indices<'o>(
+        &self,
+        (row, column): (usize, usize),
+        offsets: &'o [(isize, isize)],
+        wrapping: bool,
+    ) -> Result<NeighborIndices<'o>, Error> {
+        if row >= self.num_rows || column >= self.num_columns {
+            return Err(self.indices_out_of_bounds(row, column));
+        }
+        Ok(NeighborIndices {
+            center: (row, column),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            wrapping,
+            offsets: offsets.iter(),
+        })
+    }
+
+    fn neighbors_mut<'a>(
+        &'a mut self,
+        indices: NeighborIndices<'a>,
+    ) -> Result<NeighborsMut<'a, T>, Error>
+    where
+        S: StorageMut<T>,
+    {
+        for (position, (row, column)) in indices.clone().enumerate() {
+            if indices
+                .clone()
+                .take(position)
+                .any(|other| other == (row, column))
+            {
+                return Err(Error::DuplicateIndices { row, column });
+            }
+        }
+        Ok(NeighborsMut {
+            indices,
+            ptr: self.array.as_mut_ptr(),
+            layout: self.layout,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// An [`Iterator`] over the indices of the neighbors of an element of an
+/// [`Array2D`], created by [`Array2D::neighbors_with_indices`] and friends.
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::neighbors_with_indices`]: struct.Array2D.html#method.neighbors_with_indices
+#[derive(Debug, Clone)]
+pub struct NeighborIndices<'o> {
+    center: (usize, usize),
+    num_rows: usize,
+    num_columns: usize,
+    wrapping: bool,
+    offsets: slice::Iter<'o, (isize, isize)>,
+}
+
+impl NeighborIndices<'_> {
+    /// The index at the given offset from the center, if it is in bounds or
+    /// the offsets wrap around the edges.
+    fn target(&self, (row_offset, column_offset): (isize, isize)) -> Option<(usize, usize)> {
+        if self.wrapping {
+            return Some((
+                wrap(self.center.0, row_offset, self.num_rows),
+                wrap(self.center.1, column_offset, self.num_columns),
+            ));
+        }
+        let row = self.center.0.checked_add_signed(row_offset)?;
+        let column = self.center.1.checked_add_signed(column_offset)?;
+        (row < self.num_rows && column < self.num_columns).then_some((row, column))
+    }
+}
+
+impl Iterator for NeighborIndices<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&offset) = self.offsets.next() {
+            if let Some(index) = self.target(offset) {
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.offsets.len()))
+    }
+}
+
+impl DoubleEndedIterator for NeighborIndices<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(&offset) = self.offsets.next_back() {
+            if let Some(index) = self.target(offset) {
+                return Some(index);
+            }
+        }
+        None
+    }
+}
+
+impl FusedIterator for NeighborIndices<'_> {}
+
+/// An [`Iterator`] over the neighbors of an element of an [`Array2D`] along
+/// with their indices, created by [`Array2D::neighbors_with`] and friends.
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::neighbors_with`]: struct.Array2D.html#method.neighbors_with
+#[derive(Debug)]
+pub struct Neighbors<'a, T> {
+    indices: NeighborIndices<'a>,
+    array: Array2D<T, &'a [T]>,
+}
+
+impl<T> Clone for Neighbors<'_, T> {
+    fn clone(&self) -> Self {
+        Neighbors {
+            indices: self.indices.clone(),
+            array: self.array,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Neighbors<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        Some((index, self.array.element(index)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Neighbors<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back()?;
+        Some((index, self.array.element(index)))
+    }
+}
+
+impl<T> FusedIterator for Neighbors<'_, T> {}
+
+/// An [`Iterator`] over the neighbors of an element of an [`Array2D`] along
+/// with their indices, with mutable references, created by
+/// [`Array2D::neighbors_with_mut`] and friends.
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::neighbors_with_mut`]: struct.Array2D.html#method.neighbors_with_mut
+#[derive(Debug)]
+pub struct NeighborsMut<'a, T> {
+    indices: NeighborIndices<'a>,
+    ptr: *mut T,
+    layout: Layout,
+    marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: `NeighborsMut` behaves like a collection of `&mut T`.
+unsafe impl<T: Send> Send for NeighborsMut<'_, T> {}
+// SAFETY: `NeighborsMut` only hands out references through `&mut self`.
+unsafe impl<T: Sync> Sync for NeighborsMut<'_, T> {}
+
+impl<'a, T> NeighborsMut<'a, T> {
+    fn element(&self, (row, column): (usize, usize)) -> &'a mut T {
+        let (num_rows, num_columns) = (self.indices.num_rows, self.indices.num_columns);
+        let index = self.layout.index(row, column, num_rows, num_columns);
+        // SAFETY: The index is in bounds, and every index was checked to be
+        // distinct, so no element is produced twice.
+        unsafe { &mut *self.ptr.add(index) }
+    }
+}
+
+impl<'a, T> Iterator for NeighborsMut<'a, T> {
+    type Item = ((usize, usize), &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        Some((index, self.element(index)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for NeighborsMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back()?;
+        Some((index, self.element(index)))
+    }
+}
+
+impl<T> FusedIterator for NeighborsMut<'_, T> {}
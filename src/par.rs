@@ -0,0 +1,248 @@
+//! Parallel iteration and construction of an [`Array2D`] using [`rayon`].
+//! Only available with the `rayon` feature.
+//!
+//! The work is only ever split between rows, so every worker handles whole
+//! rows, which are contiguous in memory unless the array has a column major
+//! [`Layout`].
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+//! [`rayon`]: https://docs.rs/rayon
+//! [`Layout`]: ../enum.Layout.html
+
+use crate::{Array2D, Layout, RowIter, RowIterMut, Storage, StorageMut};
+use rayon::iter::Either;
+use rayon::prelude::*;
+use std::marker::PhantomData;
+
+impl<T> Array2D<T> {
+    /// Creates a new [`Array2D`] with the specified number of rows and columns
+    /// by calling `generator` with the index of every element, in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::Array2D;
+    /// let array = Array2D::par_from_fn(2, 3, |(row, column)| row * 10 + column);
+    /// assert_eq!(array.as_rows(), vec![vec![0, 1, 2], vec![10, 11, 12]]);
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn par_from_fn<F>(num_rows: usize, num_columns: usize, generator: F) -> Self
+    where
+        F: Fn((usize, usize)) -> T + Sync + Send,
+        T: Send,
+    {
+        let mut array = Vec::with_capacity(
+            crate::total_len(num_rows, num_columns).expect("array dimensions overflow usize"),
+        );
+        array.par_extend(
+            (0..num_rows)
+                .into_par_iter()
+                .flat_map_iter(|row| (0..num_columns).map(move |column| (row, column)))
+                .map(generator),
+        );
+        Array2D {
+            array,
+            num_rows,
+            num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns a [`ParallelIterator`] over all rows, in order. Each row is
+    /// given as a [`RowIter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # use rayon::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let sums = array
+    ///     .par_rows_iter()
+    ///     .map(|row| row.sum())
+    ///     .collect::<Vec<i32>>();
+    /// assert_eq!(sums, vec![6, 15]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ParallelIterator`]: https://docs.rs/rayon/latest/rayon/iter/trait.ParallelIterator.html
+    /// [`RowIter`]: struct.RowIter.html
+    pub fn par_rows_iter(&self) -> impl IndexedParallelIterator<Item = RowIter<'_, T>>
+    where
+        S: Sync,
+        T: Sync,
+    {
+        (0..self.num_rows)
+            .into_par_iter()
+            .map(move |row| self.row_iter(row).expect("par_rows_iter should never fail"))
+    }
+
+    /// Returns a [`ParallelIterator`] over all rows, in order. Each row is
+    /// given as a [`RowIterMut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # use rayon::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// array
+    ///     .par_rows_iter_mut()
+    ///     .enumerate()
+    ///     .for_each(|(row, elements)| elements.for_each(|element| *element += row * 10));
+    /// assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![14, 15, 16]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ParallelIterator`]: https://docs.rs/rayon/latest/rayon/iter/trait.ParallelIterator.html
+    /// [`RowIterMut`]: struct.RowIterMut.html
+    pub fn par_rows_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = RowIterMut<'_, T>>
+    where
+        S: StorageMut<T>,
+        T: Send,
+    {
+        match self.layout {
+            Layout::RowMajor if self.num_columns > 0 => {
+                Either::Left(self.array.par_chunks_mut(self.num_columns).map(|row| {
+                    // SAFETY: Each chunk is a distinct row of contiguous
+                    // elements borrowed for as long as the array.
+                    unsafe { RowIterMut::new(row.as_mut_ptr(), row.len(), 1) }
+                }))
+            }
+            // The rows are not contiguous, or are empty and cannot be made
+            // from chunks, so they are split up front.
+            _ => Either::Right(self.rows_iter_mut().collect::<Vec<_>>().into_par_iter()),
+        }
+    }
+
+    /// Returns a [`ParallelIterator`] over references to all elements in [row
+    /// major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # use rayon::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(array.par_elements_iter().sum::<i32>(), 21);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ParallelIterator`]: https://docs.rs/rayon/latest/rayon/iter/trait.ParallelIterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn par_elements_iter(&self) -> impl ParallelIterator<Item = &T>
+    where
+        S: Sync,
+        T: Sync,
+    {
+        self.par_rows_iter().flat_map_iter(|row| row)
+    }
+
+    /// Returns a [`ParallelIterator`] over mutable references to all elements
+    /// in [row major order].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # use rayon::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// array.par_elements_iter_mut().for_each(|element| *element *= 10);
+    /// assert_eq!(array.as_rows(), vec![vec![10, 20, 30], vec![40, 50, 60]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ParallelIterator`]: https://docs.rs/rayon/latest/rayon/iter/trait.ParallelIterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn par_elements_iter_mut(&mut self) -> impl ParallelIterator<Item = &mut T>
+    where
+        S: StorageMut<T>,
+        T: Send,
+    {
+        self.par_rows_iter_mut().flat_map_iter(|row| row)
+    }
+
+    /// Returns a [`ParallelIterator`] over all elements in [row major order]
+    /// along with the corresponding indices. Each index is a tuple of
+    /// [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # use rayon::prelude::*;
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2], vec![3, 4]])?;
+    /// let enumerated = array.par_enumerate().collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     enumerated,
+    ///     vec![((0, 0), &1), ((0, 1), &2), ((1, 0), &3), ((1, 1), &4)]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ParallelIterator`]: https://docs.rs/rayon/latest/rayon/iter/trait.ParallelIterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    pub fn par_enumerate(&self) -> impl ParallelIterator<Item = ((usize, usize), &T)>
+    where
+        S: Sync,
+        T: Sync,
+    {
+        self.par_rows_iter()
+            .enumerate()
+            .flat_map_iter(|(row, elements)| {
+                elements
+                    .enumerate()
+                    .map(move |(column, element)| ((row, column), element))
+            })
+    }
+
+    /// Creates a new [`Array2D`] by calling `mapper` on every element of this
+    /// one, in parallel. The result is the same as [`map_row_major`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// let doubled = array.par_map(|x| x * 2);
+    /// assert_eq!(doubled.as_rows(), vec![vec![2, 4, 6], vec![8, 10, 12]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`map_row_major`]: struct.Array2D.html#method.map_row_major
+    pub fn par_map<F, U>(&self, mapper: F) -> Array2D<U>
+    where
+        S: Sync,
+        F: Fn(&T) -> U + Sync + Send,
+        T: Sync,
+        U: Send,
+    {
+        let mut array = Vec::with_capacity(self.num_elements());
+        array.par_extend(self.par_elements_iter().map(mapper));
+        Array2D {
+            array,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
+        }
+    }
+}
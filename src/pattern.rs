@@ -0,0 +1,590 @@
+//! Finding every occurrence of a smaller [`Array2D`] inside a larger one.
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{Array2D, Layout, Storage};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::vec;
+
+/// Multipliers of the polynomial hashes along rows and along columns. Both are
+/// odd so that no information is lost when they wrap around.
+const ROW_BASE: u64 = 0x0000_0100_0000_01b3;
+const COLUMN_BASE: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// One of the eight ways to rotate or reflect an [`Array2D`], which together
+/// form the [dihedral group] of the square.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::{Array2D, Error, Orientation};
+/// # fn main() -> Result<(), Error> {
+/// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+/// let oriented = |orientation| array.oriented(orientation).as_rows();
+/// assert_eq!(oriented(Orientation::Identity), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+/// assert_eq!(oriented(Orientation::Rotate90), vec![vec![4, 1], vec![5, 2], vec![6, 3]]);
+/// assert_eq!(oriented(Orientation::Rotate180), vec![vec![6, 5, 4], vec![3, 2, 1]]);
+/// assert_eq!(oriented(Orientation::Rotate270), vec![vec![3, 6], vec![2, 5], vec![1, 4]]);
+/// assert_eq!(oriented(Orientation::FlipHorizontal), vec![vec![3, 2, 1], vec![6, 5, 4]]);
+/// assert_eq!(oriented(Orientation::FlipVertical), vec![vec![4, 5, 6], vec![1, 2, 3]]);
+/// assert_eq!(oriented(Orientation::Transpose), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+/// assert_eq!(oriented(Orientation::AntiTranspose), vec![vec![6, 3], vec![5, 2], vec![4, 1]]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [dihedral group]: https://en.wikipedia.org/wiki/Dihedral_group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// Leaves the array as it is.
+    Identity,
+    /// Rotates the array a quarter turn clockwise.
+    Rotate90,
+    /// Rotates the array a half turn.
+    Rotate180,
+    /// Rotates the array three quarter turns clockwise, i.e. a quarter turn
+    /// counterclockwise.
+    Rotate270,
+    /// Reflects the array left to right.
+    FlipHorizontal,
+    /// Reflects the array top to bottom.
+    FlipVertical,
+    /// Reflects the array across its main diagonal, swapping rows and
+    /// columns.
+    Transpose,
+    /// Reflects the array across its anti-diagonal.
+    AntiTranspose,
+}
+
+impl Orientation {
+    /// All eight orientations, starting with the rotations.
+    pub const ALL: [Orientation; 8] = [
+        Orientation::Identity,
+        Orientation::Rotate90,
+        Orientation::Rotate180,
+        Orientation::Rotate270,
+        Orientation::FlipHorizontal,
+        Orientation::FlipVertical,
+        Orientation::Transpose,
+        Orientation::AntiTranspose,
+    ];
+
+    fn swaps_dimensions(self) -> bool {
+        matches!(
+            self,
+            Orientation::Rotate90
+                | Orientation::Rotate270
+                | Orientation::Transpose
+                | Orientation::AntiTranspose
+        )
+    }
+
+    /// The index in the original array of the element that ends up at `(row,
+    /// column)` once an array of the given dimensions is oriented.
+    fn source(
+        self,
+        (row, column): (usize, usize),
+        num_rows: usize,
+        num_columns: usize,
+    ) -> (usize, usize) {
+        let last_row = num_rows - 1;
+        let last_column = num_columns - 1;
+        match self {
+            Orientation::Identity => (row, column),
+            Orientation::Rotate90 => (last_row - column, row),
+            Orientation::Rotate180 => (last_row - row, last_column - column),
+            Orientation::Rotate270 => (column, last_column - row),
+            Orientation::FlipHorizontal => (row, last_column - column),
+            Orientation::FlipVertical => (last_row - row, column),
+            Orientation::Transpose => (column, row),
+            Orientation::AntiTranspose => (last_row - column, last_column - row),
+        }
+    }
+}
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Creates a new [`Array2D`] by rotating or reflecting this one according
+    /// to `orientation`. The number of rows and columns are swapped for the
+    /// orientations that turn rows into columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error, Orientation};
+    /// # fn main() -> Result<(), Error> {
+    /// let piece = Array2D::from_rows(&[vec![1, 1, 1], vec![0, 1, 0]])?;
+    /// let rotated = piece.oriented(Orientation::Rotate90);
+    /// assert_eq!(rotated.as_rows(), vec![vec![0, 1], vec![1, 1], vec![0, 1]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn oriented(&self, orientation: Orientation) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        orient(self, orientation).into_map(T::clone)
+    }
+
+    /// Returns an [`Iterator`] over the indices of the top left corner of
+    /// every occurrence of `pattern` in this array, in [row major order].
+    /// Occurrences may overlap. An empty pattern, or one larger than the
+    /// array, is never found.
+    ///
+    /// Candidate positions are found by comparing a rolling hash of each
+    /// block of the array with a hash of the pattern, which takes time
+    /// proportional to the number of elements of the array rather than to
+    /// that number multiplied by the size of the pattern. Every candidate is
+    /// then compared element by element, so hash collisions never produce
+    /// false matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Array2D::from_rows(&[
+    ///     vec![0, 1, 0, 0],
+    ///     vec![1, 1, 1, 0],
+    ///     vec![0, 1, 1, 1],
+    /// ])?;
+    /// let pattern = Array2D::from_rows(&[vec![1, 1], vec![1, 1]])?;
+    /// let matches = board.find_pattern(&pattern).collect::<Vec<_>>();
+    /// assert_eq!(matches, vec![(1, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn find_pattern<R>(&self, pattern: &Array2D<T, R>) -> PatternMatches
+    where
+        R: Storage<T>,
+        T: Hash + Eq,
+    {
+        PatternMatches(hashed_matches(self, &orient(pattern, Orientation::Identity)).into_iter())
+    }
+
+    /// Returns an [`Iterator`] over the indices of the top left corner of
+    /// every occurrence of `pattern` in this array, in [row major order],
+    /// where the [`None`] elements of `pattern` match any element. Occurrences
+    /// may overlap. An empty pattern, or one larger than the array, is never
+    /// found.
+    ///
+    /// Like [`find_pattern`], candidate positions are found with a rolling
+    /// hash, here of the longest run of non-wildcard elements in a row of the
+    /// pattern, and every candidate is then compared element by element. The
+    /// fewer non-wildcard elements that run has, the more candidates need to
+    /// be compared, so patterns with a wildcard in almost every other element
+    /// are closer to comparing the whole pattern at every position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Array2D::from_rows(&[
+    ///     vec!['#', '#', '.'],
+    ///     vec!['#', '#', '#'],
+    ///     vec!['.', '#', '.'],
+    /// ])?;
+    /// let pattern = Array2D::from_rows(&[vec![Some('#'), None], vec![Some('#'), Some('#')]])?;
+    /// let matches = board.find_pattern_with_wildcards(&pattern).collect::<Vec<_>>();
+    /// assert_eq!(matches, vec![(0, 0), (0, 1)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [`find_pattern`]: struct.Array2D.html#method.find_pattern
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn find_pattern_with_wildcards<R>(&self, pattern: &Array2D<Option<T>, R>) -> PatternMatches
+    where
+        R: Storage<Option<T>>,
+        T: Hash + Eq,
+    {
+        PatternMatches(wildcard_matches(self, &orient(pattern, Orientation::Identity)).into_iter())
+    }
+
+    /// Like [`find_pattern`], but also finds `pattern` rotated or reflected
+    /// by any of the given orientations (see [`Orientation::ALL`]). Each
+    /// [`Item`] is a tuple of the index and the orientation that matched.
+    /// Matches are ordered by index, then by the order of `orientations`.
+    /// When several orientations give the same pattern, as with symmetric
+    /// ones, only the first of them is reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error, Orientation};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Array2D::from_rows(&[
+    ///     vec![1, 0, 0, 1, 1],
+    ///     vec![1, 0, 0, 0, 1],
+    ///     vec![1, 1, 0, 0, 1],
+    /// ])?;
+    /// let l_piece = Array2D::from_rows(&[vec![1, 0], vec![1, 0], vec![1, 1]])?;
+    /// let matches = board
+    ///     .find_pattern_oriented(&l_piece, &Orientation::ALL)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     matches,
+    ///     vec![((0, 0), Orientation::Identity), ((0, 3), Orientation::Rotate180)]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_pattern`]: struct.Array2D.html#method.find_pattern
+    /// [`Orientation::ALL`]: enum.Orientation.html#associatedconstant.ALL
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    pub fn find_pattern_oriented<R>(
+        &self,
+        pattern: &Array2D<T, R>,
+        orientations: &[Orientation],
+    ) -> OrientedPatternMatches
+    where
+        R: Storage<T>,
+        T: Hash + Eq,
+    {
+        let matches = oriented_matches(pattern, orientations, |pattern| {
+            hashed_matches(self, pattern)
+        });
+        OrientedPatternMatches(matches.into_iter())
+    }
+
+    /// Like [`find_pattern_with_wildcards`], but also finds `pattern` rotated
+    /// or reflected by any of the given orientations, in the same way as
+    /// [`find_pattern_oriented`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error, Orientation};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Array2D::from_rows(&[vec!['#', '.', '#'], vec!['#', '#', '#']])?;
+    /// let pattern = Array2D::from_rows(&[vec![Some('#')], vec![None], vec![Some('#')]])?;
+    /// let matches = board
+    ///     .find_pattern_oriented_with_wildcards(&pattern, &Orientation::ALL)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     matches,
+    ///     vec![((0, 0), Orientation::Rotate90), ((1, 0), Orientation::Rotate90)]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`find_pattern_with_wildcards`]: struct.Array2D.html#method.find_pattern_with_wildcards
+    /// [`find_pattern_oriented`]: struct.Array2D.html#method.find_pattern_oriented
+    pub fn find_pattern_oriented_with_wildcards<R>(
+        &self,
+        pattern: &Array2D<Option<T>, R>,
+        orientations: &[Orientation],
+    ) -> OrientedPatternMatches
+    where
+        R: Storage<Option<T>>,
+        T: Hash + Eq,
+    {
+        let matches = oriented_matches(pattern, orientations, |pattern| {
+            wildcard_matches(self, pattern)
+        });
+        OrientedPatternMatches(matches.into_iter())
+    }
+}
+
+/// An [`Iterator`] over the indices where a pattern occurs in an [`Array2D`],
+/// created by [`Array2D::find_pattern`] and
+/// [`Array2D::find_pattern_with_wildcards`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::find_pattern`]: struct.Array2D.html#method.find_pattern
+/// [`Array2D::find_pattern_with_wildcards`]: struct.Array2D.html#method.find_pattern_with_wildcards
+#[derive(Debug, Clone)]
+pub struct PatternMatches(vec::IntoIter<(usize, usize)>);
+
+/// An [`Iterator`] over the indices and orientations with which a pattern
+/// occurs in an [`Array2D`], created by [`Array2D::find_pattern_oriented`]
+/// and [`Array2D::find_pattern_oriented_with_wildcards`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::find_pattern_oriented`]: struct.Array2D.html#method.find_pattern_oriented
+/// [`Array2D::find_pattern_oriented_with_wildcards`]: struct.Array2D.html#method.find_pattern_oriented_with_wildcards
+#[derive(Debug, Clone)]
+pub struct OrientedPatternMatches(vec::IntoIter<((usize, usize), Orientation)>);
+
+macro_rules! impl_matches_iterator {
+    ($name:ident => $item:ty) => {
+        impl Iterator for $name {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        impl DoubleEndedIterator for $name {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.next_back()
+            }
+        }
+
+        impl ExactSizeIterator for $name {}
+
+        impl FusedIterator for $name {}
+    };
+}
+
+impl_matches_iterator!(PatternMatches => (usize, usize));
+impl_matches_iterator!(OrientedPatternMatches => ((usize, usize), Orientation));
+
+/// Borrows the elements of `array` in the arrangement given by
+/// `orientation`.
+fn orient<T, S: Storage<T>>(array: &Array2D<T, S>, orientation: Orientation) -> Array2D<&T> {
+    let (num_rows, num_columns) = if orientation.swaps_dimensions() {
+        (array.num_columns, array.num_rows)
+    } else {
+        (array.num_rows, array.num_columns)
+    };
+    let elements = (0..num_rows * num_columns)
+        .map(|index| {
+            let position = (index / num_columns, index % num_columns);
+            &array[orientation.source(position, array.num_rows, array.num_columns)]
+        })
+        .collect();
+    Array2D {
+        array: elements,
+        num_rows,
+        num_columns,
+        layout: Layout::RowMajor,
+        marker: PhantomData,
+    }
+}
+
+/// The number of rows and columns at which the top left corner of `pattern`
+/// can be placed within `array`, if any.
+fn placements<T, P, S, R>(
+    array: &Array2D<T, S>,
+    pattern: &Array2D<P, R>,
+) -> Option<(usize, usize)> {
+    if pattern.num_rows == 0 || pattern.num_columns == 0 {
+        return None;
+    }
+    let num_rows = array.num_rows.checked_sub(pattern.num_rows)? + 1;
+    let num_columns = array.num_columns.checked_sub(pattern.num_columns)? + 1;
+    Some((num_rows, num_columns))
+}
+
+fn matches_at<T, P, S, R, F>(
+    array: &Array2D<T, S>,
+    pattern: &Array2D<P, R>,
+    (row, column): (usize, usize),
+    mut equal: F,
+) -> bool
+where
+    S: Storage<T>,
+    R: Storage<P>,
+    F: FnMut(&T, &P) -> bool,
+{
+    pattern
+        .enumerate_row_major()
+        .all(|((pattern_row, pattern_column), element)| {
+            equal(
+                &array[(row + pattern_row, column + pattern_column)],
+                element,
+            )
+        })
+}
+
+fn hashed_matches<T, S>(array: &Array2D<T, S>, pattern: &Array2D<&T>) -> Vec<(usize, usize)>
+where
+    S: Storage<T>,
+    T: Hash + Eq,
+{
+    let (num_rows, num_columns) = match placements(array, pattern) {
+        Some(placements) => placements,
+        None => return Vec::new(),
+    };
+    let state = RandomState::new();
+
+    let pattern_rows = pattern
+        .rows_iter()
+        .map(|row| {
+            let row = row
+                .map(|element| state.hash_one(element))
+                .collect::<Vec<_>>();
+            window_hashes(&row, pattern.num_columns, ROW_BASE)[0]
+        })
+        .collect::<Vec<_>>();
+    let pattern_hash = window_hashes(&pattern_rows, pattern.num_rows, COLUMN_BASE)[0];
+
+    // The hashes of every block with the width of the pattern, grouped by
+    // the column they start at so that they can be rolled down each column.
+    let mut columns = vec![Vec::with_capacity(array.num_rows); num_columns];
+    for row in array.rows_iter() {
+        let row = row
+            .map(|element| state.hash_one(element))
+            .collect::<Vec<_>>();
+        let hashes = window_hashes(&row, pattern.num_columns, ROW_BASE);
+        for (column, hash) in columns.iter_mut().zip(hashes) {
+            column.push(hash);
+        }
+    }
+    let blocks = columns
+        .iter()
+        .map(|column| window_hashes(column, pattern.num_rows, COLUMN_BASE))
+        .collect::<Vec<_>>();
+
+    let mut matches = Vec::new();
+    for row in 0..num_rows {
+        for (column, hashes) in blocks.iter().enumerate() {
+            if hashes[row] == pattern_hash
+                && matches_at(array, pattern, (row, column), |element, &pattern| {
+                    element == pattern
+                })
+            {
+                matches.push((row, column));
+            }
+        }
+    }
+    matches
+}
+
+/// The polynomial hashes of every run of `window` consecutive values, which
+/// must be at least one and at most the number of values.
+fn window_hashes(values: &[u64], window: usize, base: u64) -> Vec<u64> {
+    let leading = (1..window).fold(1u64, |power, _| power.wrapping_mul(base));
+    let mut hash = values[..window].iter().fold(0u64, |hash, &value| {
+        hash.wrapping_mul(base).wrapping_add(value)
+    });
+    let mut hashes = Vec::with_capacity(values.len() - window + 1);
+    hashes.push(hash);
+    for (&old, &new) in values.iter().zip(&values[window..]) {
+        hash = hash
+            .wrapping_sub(old.wrapping_mul(leading))
+            .wrapping_mul(base)
+            .wrapping_add(new);
+        hashes.push(hash);
+    }
+    hashes
+}
+
+fn wildcard_matches<T, S>(
+    array: &Array2D<T, S>,
+    pattern: &Array2D<&Option<T>>,
+) -> Vec<(usize, usize)>
+where
+    S: Storage<T>,
+    T: Hash + Eq,
+{
+    let (num_rows, num_columns) = match placements(array, pattern) {
+        Some(placements) => placements,
+        None => return Vec::new(),
+    };
+    let positions = (0..num_rows).flat_map(|row| (0..num_columns).map(move |column| (row, column)));
+    let (run_row, run_column, run_len) = longest_run(pattern);
+    if run_len == 0 {
+        // A pattern of wildcards matches everywhere.
+        return positions.collect();
+    }
+    let state = RandomState::new();
+
+    let run = pattern
+        .row_iter(run_row)
+        .expect("run row is in bounds")
+        .skip(run_column)
+        .take(run_len)
+        .map(|element| state.hash_one(element.as_ref().expect("run has no wildcards")))
+        .collect::<Vec<_>>();
+    let run_hash = window_hashes(&run, run_len, ROW_BASE)[0];
+
+    // The hashes of every run of the same length in the rows of the array
+    // that the run of the pattern can be placed on.
+    let rows = array
+        .rows_iter()
+        .skip(run_row)
+        .take(num_rows)
+        .map(|row| {
+            let row = row
+                .map(|element| state.hash_one(element))
+                .collect::<Vec<_>>();
+            window_hashes(&row, run_len, ROW_BASE)
+        })
+        .collect::<Vec<_>>();
+
+    positions
+        .filter(|&(row, column)| {
+            rows[row][column + run_column] == run_hash
+                && matches_at(
+                    array,
+                    pattern,
+                    (row, column),
+                    |element, pattern| match pattern {
+                        Some(pattern) => element == pattern,
+                        None => true,
+                    },
+                )
+        })
+        .collect()
+}
+
+/// The row, first column and length of the longest run of consecutive
+/// non-wildcard elements in a row of `pattern`, or a length of zero if every
+/// element is a wildcard.
+fn longest_run<T>(pattern: &Array2D<&Option<T>>) -> (usize, usize, usize) {
+    let mut longest = (0, 0, 0);
+    for (row, elements) in pattern.rows_iter().enumerate() {
+        let mut len = 0;
+        for (column, element) in elements.enumerate() {
+            len = if element.is_some() { len + 1 } else { 0 };
+            if len > longest.2 {
+                longest = (row, column + 1 - len, len);
+            }
+        }
+    }
+    longest
+}
+
+fn oriented_matches<'p, P, R, F>(
+    pattern: &'p Array2D<P, R>,
+    orientations: &[Orientation],
+    mut find: F,
+) -> Vec<((usize, usize), Orientation)>
+where
+    R: Storage<P>,
+    P: PartialEq,
+    F: FnMut(&Array2D<&'p P>) -> Vec<(usize, usize)>,
+{
+    let mut patterns: Vec<(Orientation, Array2D<&P>)> = Vec::new();
+    for &orientation in orientations {
+        let oriented = orient(pattern, orientation);
+        if patterns.iter().all(|(_, other)| *other != oriented) {
+            patterns.push((orientation, oriented));
+        }
+    }
+    let mut matches = Vec::new();
+    for (order, (orientation, oriented)) in patterns.iter().enumerate() {
+        matches.extend(
+            find(oriented)
+                .into_iter()
+                .map(|position| (position, order, *orientation)),
+        );
+    }
+    matches.sort_unstable_by_key(|&(position, order, _)| (position, order));
+    matches
+        .into_iter()
+        .map(|(position, _, orientation)| (position, orientation))
+        .collect()
+}
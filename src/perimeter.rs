@@ -0,0 +1,428 @@
+//! Iterators along the border of an [`Array2D`], around rings of elements,
+//! and in a spiral.
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::iter::Positions;
+use crate::{Array2D, Error, Storage};
+use std::iter::FusedIterator;
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns an [`Iterator`] over the elements on the border of the array,
+    /// clockwise from the top left corner. Each [`Item`] is a tuple of the
+    /// index and a reference to the element. Every element is produced once,
+    /// so a single row or column is walked from one end to the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]])?;
+    /// let border = array.border_iter().map(|(_, &x)| x).collect::<Vec<_>>();
+    /// assert_eq!(border, vec![1, 2, 3, 6, 9, 8, 7, 4]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    pub fn border_iter(&self) -> PerimeterIter<'_, T> {
+        let perimeter = if self.num_rows == 0 || self.num_columns == 0 {
+            Perimeter::default()
+        } else {
+            Perimeter::new(
+                (0, 0),
+                (self.num_rows as isize - 1, self.num_columns as isize - 1),
+                (self.num_rows, self.num_columns),
+            )
+        };
+        PerimeterIter::new(self.borrowed(), perimeter)
+    }
+
+    /// Returns an [`Iterator`] over the elements at [Chebyshev distance] `k`
+    /// from the element at the given index, i.e. the square ring of elements
+    /// `k` steps away, clockwise from its top left corner. Elements of the ring
+    /// outside the array are skipped. Each [`Item`] is a tuple of the index
+    /// and a reference to the element. Returns an error if the index is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_iter_row_major(4, 4, 0..)?;
+    /// let ring = array.ring_iter((1, 1), 1)?.map(|(_, &x)| x).collect::<Vec<_>>();
+    /// assert_eq!(ring, vec![0, 1, 2, 6, 10, 9, 8, 4]);
+    ///
+    /// let clipped = array.ring_iter((0, 0), 2)?.map(|(index, _)| index).collect::<Vec<_>>();
+    /// assert_eq!(clipped, vec![(0, 2), (1, 2), (2, 2), (2, 1), (2, 0)]);
+    ///
+    /// assert_eq!(array.ring_iter((0, 0), 0)?.next(), Some(((0, 0), &0)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [Chebyshev distance]: https://en.wikipedia.org/wiki/Chebyshev_distance
+    pub fn ring_iter(
+        &self,
+        (row, column): (usize, usize),
+        k: usize,
+    ) -> Result<PerimeterIter<'_, T>, Error> {
+        if row >= self.num_rows || column >= self.num_columns {
+            return Err(self.indices_out_of_bounds(row, column));
+        }
+        // A ring further away than the largest dimension misses the array
+        // entirely, and every closer ring fits in an `isize`.
+        let perimeter = if k >= self.num_rows.max(self.num_columns) {
+            Perimeter::default()
+        } else {
+            let (row, column, k) = (row as isize, column as isize, k as isize);
+            Perimeter::new(
+                (row - k, column - k),
+                (row + k, column + k),
+                (self.num_rows, self.num_columns),
+            )
+        };
+        Ok(PerimeterIter::new(self.borrowed(), perimeter))
+    }
+
+    /// Returns an [`Iterator`] over all elements in a clockwise spiral from
+    /// the outside in, starting at the top left corner. Each [`Item`] is a
+    /// tuple of the index and a reference to the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]])?;
+    /// let spiral = array.spiral_iter().map(|(_, &x)| x).collect::<Vec<_>>();
+    /// assert_eq!(spiral, vec![1, 2, 3, 6, 9, 8, 7, 4, 5]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    pub fn spiral_iter(&self) -> SpiralIter<'_, T> {
+        let num_elements = self.num_elements();
+        SpiralIter {
+            array: self.borrowed(),
+            positions: Positions::new(num_elements),
+            front: Layer::new(0, 0, self.num_rows, self.num_columns),
+            back: Layer::last(self.num_rows, self.num_columns, num_elements),
+        }
+    }
+}
+
+/// A straight run of elements from `start` in the direction of `step`.
+#[derive(Debug, Clone, Copy, Default)]
+struct Segment {
+    start: (usize, usize),
+    step: (isize, isize),
+    len: usize,
+}
+
+impl Segment {
+    /// The part of the run of `len` elements from `start` in the direction of
+    /// `step` that lies within `dimensions`, if any.
+    fn clipped(
+        start: (isize, isize),
+        step: (isize, isize),
+        len: usize,
+        (num_rows, num_columns): (usize, usize),
+    ) -> Option<Self> {
+        let rows = steps_in_bounds(start.0, step.0, num_rows, len)?;
+        let columns = steps_in_bounds(start.1, step.1, num_columns, len)?;
+        let (low, high) = (rows.0.max(columns.0), rows.1.min(columns.1));
+        if low > high {
+            return None;
+        }
+        let start = (
+            (start.0 + low as isize * step.0) as usize,
+            (start.1 + low as isize * step.1) as usize,
+        );
+        Some(Segment {
+            start,
+            step,
+            len: high - low + 1,
+        })
+    }
+
+    fn get(&self, position: usize) -> (usize, usize) {
+        let offset = |start: usize, step: isize| match step {
+            1 => start + position,
+            -1 => start - position,
+            _ => start,
+        };
+        (
+            offset(self.start.0, self.step.0),
+            offset(self.start.1, self.step.1),
+        )
+    }
+}
+
+/// The range of steps `0..len` for which `start + steps * step` lies within
+/// `0..bound`, as an inclusive range.
+fn steps_in_bounds(start: isize, step: isize, bound: usize, len: usize) -> Option<(usize, usize)> {
+    let last = len.checked_sub(1)? as isize;
+    let bound = bound as isize;
+    let (low, high) = match step {
+        0 if (0..bound).contains(&start) => (0, last),
+        0 => return None,
+        1 => (-start, bound - 1 - start),
+        _ => (start - (bound - 1), start),
+    };
+    let (low, high) = (low.max(0), high.min(last));
+    (low <= high).then_some((low as usize, high as usize))
+}
+
+/// The elements on the edge of a rectangle that lie within an array,
+/// clockwise from the top left corner, as up to four segments.
+#[derive(Debug, Clone, Copy, Default)]
+struct Perimeter {
+    segments: [Segment; 4],
+    len: usize,
+}
+
+impl Perimeter {
+    /// The perimeter of the rectangle from `top_left` to `bottom_right`,
+    /// inclusive, clipped to `dimensions`.
+    fn new(
+        (top, left): (isize, isize),
+        (bottom, right): (isize, isize),
+        dimensions: (usize, usize),
+    ) -> Self {
+        let height = (bottom - top) as usize + 1;
+        let width = (right - left) as usize + 1;
+        let mut perimeter = Perimeter::default();
+        if height == 1 {
+            perimeter.push((top, left), (0, 1), width, dimensions);
+        } else if width == 1 {
+            perimeter.push((top, left), (1, 0), height, dimensions);
+        } else {
+            perimeter.push((top, left), (0, 1), width - 1, dimensions);
+            perimeter.push((top, right), (1, 0), height - 1, dimensions);
+            perimeter.push((bottom, right), (0, -1), width - 1, dimensions);
+            perimeter.push((bottom, left), (-1, 0), height - 1, dimensions);
+        }
+        perimeter
+    }
+
+    fn push(
+        &mut self,
+        start: (isize, isize),
+        step: (isize, isize),
+        len: usize,
+        dimensions: (usize, usize),
+    ) {
+        if let Some(segment) = Segment::clipped(start, step, len, dimensions) {
+            self.segments[self.len] = segment;
+            self.len += 1;
+        }
+    }
+
+    fn segments(&self) -> &[Segment] {
+        &self.segments[..self.len]
+    }
+
+    fn num_elements(&self) -> usize {
+        self.segments().iter().map(|segment| segment.len).sum()
+    }
+
+    fn get(&self, mut position: usize) -> (usize, usize) {
+        for segment in self.segments() {
+            if position < segment.len {
+                return segment.get(position);
+            }
+            position -= segment.len;
+        }
+        unreachable!("position should be within the perimeter");
+    }
+}
+
+/// An [`Iterator`] over the elements on the perimeter of a rectangle within an
+/// [`Array2D`] along with their indices, created by [`Array2D::border_iter`]
+/// and [`Array2D::ring_iter`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::border_iter`]: struct.Array2D.html#method.border_iter
+/// [`Array2D::ring_iter`]: struct.Array2D.html#method.ring_iter
+#[derive(Debug)]
+pub struct PerimeterIter<'a, T> {
+    array: Array2D<T, &'a [T]>,
+    perimeter: Perimeter,
+    positions: Positions,
+}
+
+impl<'a, T> PerimeterIter<'a, T> {
+    fn new(array: Array2D<T, &'a [T]>, perimeter: Perimeter) -> Self {
+        PerimeterIter {
+            array,
+            perimeter,
+            positions: Positions::new(perimeter.num_elements()),
+        }
+    }
+
+    fn item(&self, position: usize) -> ((usize, usize), &'a T) {
+        let index = self.perimeter.get(position);
+        (index, self.array.element(index))
+    }
+}
+
+impl<T> Clone for PerimeterIter<'_, T> {
+    fn clone(&self) -> Self {
+        PerimeterIter {
+            array: self.array,
+            perimeter: self.perimeter,
+            positions: self.positions.clone(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for PerimeterIter<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.positions.next()?;
+        Some(self.item(position))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.positions.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for PerimeterIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let position = self.positions.next_back()?;
+        Some(self.item(position))
+    }
+}
+
+impl<T> ExactSizeIterator for PerimeterIter<'_, T> {}
+
+impl<T> FusedIterator for PerimeterIter<'_, T> {}
+
+/// One layer of a spiral: the border of the array with `depth` rows and
+/// columns removed from every side, and the position of its first element in
+/// the spiral.
+#[derive(Debug, Clone, Copy)]
+struct Layer {
+    depth: usize,
+    start: usize,
+    perimeter: Perimeter,
+}
+
+impl Layer {
+    fn new(depth: usize, start: usize, num_rows: usize, num_columns: usize) -> Self {
+        let perimeter = if num_rows <= 2 * depth || num_columns <= 2 * depth {
+            Perimeter::default()
+        } else {
+            let (top_left, bottom, right) = (
+                depth as isize,
+                (num_rows - 1 - depth) as isize,
+                (num_columns - 1 - depth) as isize,
+            );
+            Perimeter::new(
+                (top_left, top_left),
+                (bottom, right),
+                (num_rows, num_columns),
+            )
+        };
+        Layer {
+            depth,
+            start,
+            perimeter,
+        }
+    }
+
+    /// The innermost layer of the spiral, which holds every element not in
+    /// an outer layer.
+    fn last(num_rows: usize, num_columns: usize, num_elements: usize) -> Self {
+        let num_layers = num_rows.min(num_columns).div_ceil(2);
+        let depth = num_layers.saturating_sub(1);
+        let start = num_elements - (num_rows - 2 * depth) * (num_columns - 2 * depth);
+        Layer::new(depth, start, num_rows, num_columns)
+    }
+
+    fn end(&self) -> usize {
+        self.start + self.perimeter.num_elements()
+    }
+}
+
+/// An [`Iterator`] over all elements of an [`Array2D`] in a clockwise spiral
+/// from the outside in, along with their indices, created by
+/// [`Array2D::spiral_iter`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::spiral_iter`]: struct.Array2D.html#method.spiral_iter
+#[derive(Debug)]
+pub struct SpiralIter<'a, T> {
+    array: Array2D<T, &'a [T]>,
+    positions: Positions,
+    front: Layer,
+    back: Layer,
+}
+
+impl<'a, T> SpiralIter<'a, T> {
+    fn layer(&self, depth: usize, start: usize) -> Layer {
+        Layer::new(depth, start, self.array.num_rows, self.array.num_columns)
+    }
+
+    fn item(&self, layer: &Layer, position: usize) -> ((usize, usize), &'a T) {
+        let index = layer.perimeter.get(position - layer.start);
+        (index, self.array.element(index))
+    }
+}
+
+impl<T> Clone for SpiralIter<'_, T> {
+    fn clone(&self) -> Self {
+        SpiralIter {
+            array: self.array,
+            positions: self.positions.clone(),
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+impl<'a, T> Iterator for SpiralIter<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.positions.next()?;
+        while position >= self.front.end() {
+            self.front = self.layer(self.front.depth + 1, self.front.end());
+        }
+        Some(self.item(&self.front, position))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.positions.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for SpiralIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let position = self.positions.next_back()?;
+        while position < self.back.start {
+            let depth = self.back.depth - 1;
+            let outer = self.layer(depth, 0);
+            self.back = self.layer(depth, self.back.start - outer.perimeter.num_elements());
+        }
+        Some(self.item(&self.back, position))
+    }
+}
+
+impl<T> ExactSizeIterator for SpiralIter<'_, T> {}
+
+impl<T> FusedIterator for SpiralIter<'_, T> {}
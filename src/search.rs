@@ -0,0 +1,297 @@
+//! Searching an [`Array2D`] for elements and returning their indices.
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{Array2D, EnumerateRowMajor, Storage};
+use std::fmt;
+use std::iter::FusedIterator;
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns the index of the first element, in [row major order], for
+    /// which `predicate` returns `true`, or [`None`] if there is no such
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Array2D::from_rows(&[vec!['.', '.', '@'], vec!['@', '.', '.']])?;
+    /// assert_eq!(board.position_row_major(|&tile| tile == '@'), Some((0, 2)));
+    /// assert_eq!(board.position_row_major(|&tile| tile == '#'), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn position_row_major<P>(&self, mut predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.enumerate_row_major()
+            .find(|(_, element)| predicate(element))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index of the first element, in [column major order], for
+    /// which `predicate` returns `true`, or [`None`] if there is no such
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Array2D::from_rows(&[vec!['.', '.', '@'], vec!['@', '.', '.']])?;
+    /// assert_eq!(board.position_column_major(|&tile| tile == '@'), Some((1, 0)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn position_column_major<P>(&self, mut predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.enumerate_column_major()
+            .find(|(_, element)| predicate(element))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index of the last element, in [row major order], for which
+    /// `predicate` returns `true`, or [`None`] if there is no such element.
+    /// The elements are searched starting from the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Array2D::from_rows(&[vec!['.', '.', '@'], vec!['@', '.', '.']])?;
+    /// assert_eq!(board.rposition_row_major(|&tile| tile == '@'), Some((1, 0)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn rposition_row_major<P>(&self, mut predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.enumerate_row_major()
+            .rfind(|(_, element)| predicate(element))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index of the last element, in [column major order], for
+    /// which `predicate` returns `true`, or [`None`] if there is no such
+    /// element. The elements are searched starting from the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Array2D::from_rows(&[vec!['.', '.', '@'], vec!['@', '.', '.']])?;
+    /// assert_eq!(board.rposition_column_major(|&tile| tile == '@'), Some((0, 2)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn rposition_column_major<P>(&self, mut predicate: P) -> Option<(usize, usize)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.enumerate_column_major()
+            .rfind(|(_, element)| predicate(element))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns an [`Iterator`] over the indices of every element, in [row
+    /// major order], for which `predicate` returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let board = Array2D::from_rows(&[vec!['.', '.', '@'], vec!['@', '.', '.']])?;
+    /// let players = board.positions(|&tile| tile == '@').collect::<Vec<_>>();
+    /// assert_eq!(players, vec![(0, 2), (1, 0)]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn positions<P>(&self, predicate: P) -> PositionsIter<'_, T, P>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        PositionsIter {
+            elements: self.enumerate_row_major(),
+            predicate,
+        }
+    }
+
+    /// Returns `true` if the array contains an element equal to `element`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert!(array.contains(&5));
+    /// assert!(!array.contains(&7));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn contains(&self, element: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.array.contains(element)
+    }
+
+    /// Returns the number of elements for which `predicate` returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(array.count(|element| element % 2 == 0), 3);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn count<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.array
+            .iter()
+            .filter(|element| predicate(element))
+            .count()
+    }
+
+    /// Returns the index of the element with the minimum value of `key`,
+    /// along with the element itself, or [`None`] if the array is empty. If
+    /// several elements are equally minimum, the first one in [row major
+    /// order] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let heights = Array2D::from_rows(&[vec![3, 1, 4], vec![1, 5, 9]])?;
+    /// assert_eq!(heights.min_by_key(|&height| height), Some(((0, 1), &1)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn min_by_key<K, F>(&self, mut key: F) -> Option<((usize, usize), &T)>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.enumerate_row_major()
+            .min_by_key(|(_, element)| key(element))
+    }
+
+    /// Returns the index of the element with the maximum value of `key`,
+    /// along with the element itself, or [`None`] if the array is empty. If
+    /// several elements are equally maximum, the last one in [row major
+    /// order] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let heights = Array2D::from_rows(&[vec![3, 9, 4], vec![1, 5, 9]])?;
+    /// assert_eq!(heights.max_by_key(|&height| height), Some(((1, 2), &9)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn max_by_key<K, F>(&self, mut key: F) -> Option<((usize, usize), &T)>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.enumerate_row_major()
+            .max_by_key(|(_, element)| key(element))
+    }
+}
+
+/// An [`Iterator`] over the indices of the elements of an [`Array2D`] that
+/// match a predicate, created by [`Array2D::positions`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::positions`]: struct.Array2D.html#method.positions
+pub struct PositionsIter<'a, T, P> {
+    elements: EnumerateRowMajor<'a, T>,
+    predicate: P,
+}
+
+impl<T: fmt::Debug, P> fmt::Debug for PositionsIter<'_, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PositionsIter")
+            .field("elements", &self.elements)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, P: Clone> Clone for PositionsIter<'_, T, P> {
+    fn clone(&self) -> Self {
+        PositionsIter {
+            elements: self.elements.clone(),
+            predicate: self.predicate.clone(),
+        }
+    }
+}
+
+impl<T, P> Iterator for PositionsIter<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.elements
+            .find(|(_, element)| predicate(element))
+            .map(|(index, _)| index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.elements.size_hint().1)
+    }
+}
+
+impl<T, P> DoubleEndedIterator for PositionsIter<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let predicate = &mut self.predicate;
+        self.elements
+            .rfind(|(_, element)| predicate(element))
+            .map(|(index, _)| index)
+    }
+}
+
+impl<T, P> FusedIterator for PositionsIter<'_, T, P> where P: FnMut(&T) -> bool {}
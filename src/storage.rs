@@ -0,0 +1,264 @@
+//! The buffers an [`Array2D`] can keep its elements in.
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{Array2D, Error, Layout};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// A buffer of elements that an [`Array2D`] can read from.
+///
+/// By default an [`Array2D`] owns its elements in a [`Vec`], but any storage
+/// that dereferences to a slice can be used instead. This makes it possible
+/// to use the whole read API of [`Array2D`] on a buffer that comes from
+/// elsewhere, such as a borrowed slice, without copying its elements (see
+/// [`from_storage`] and [`from_slice`]).
+///
+/// [`Storage`] is implemented for `Vec<T>`, `Box<[T]>`, `&[T]`, `&mut [T]`,
+/// `Rc<[T]>` and `Arc<[T]>`. Storage that can also be written to implements
+/// [`StorageMut`].
+///
+/// The trait is sealed: the iterators and views of an [`Array2D`] rely on its
+/// storage always dereferencing to the same elements, so it cannot be
+/// implemented outside of this crate.
+///
+/// ```compile_fail
+/// # use array2d::Storage;
+/// # use std::ops::Deref;
+/// struct Shrinking(Vec<u8>);
+///
+/// impl Deref for Shrinking {
+///     type Target = [u8];
+///
+///     fn deref(&self) -> &[u8] {
+///         &self.0[..1]
+///     }
+/// }
+///
+/// impl Storage<u8> for Shrinking {}
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::{Array2D, Error};
+/// # fn main() -> Result<(), Error> {
+/// let buffer = [1, 2, 3, 4, 5, 6];
+/// let array = Array2D::from_slice(2, 3, &buffer)?;
+/// assert_eq!(array[(1, 0)], 4);
+/// assert_eq!(array.row_iter(0)?.sum::<i32>(), 6);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`Storage`]: trait.Storage.html
+/// [`StorageMut`]: trait.StorageMut.html
+/// [`from_storage`]: struct.Array2D.html#method.from_storage
+/// [`from_slice`]: struct.Array2D.html#method.from_slice
+pub trait Storage<T>: Deref<Target = [T]> + sealed::Sealed {}
+
+/// A buffer of elements that an [`Array2D`] can also write to, needed for the
+/// methods that change elements.
+///
+/// [`StorageMut`] is implemented for `Vec<T>`, `Box<[T]>` and `&mut [T]`.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::{Array2D, Error};
+/// # fn main() -> Result<(), Error> {
+/// let mut frame = vec![0_u8; 6];
+/// let mut array = Array2D::from_mut_slice(2, 3, &mut frame)?;
+/// array[(1, 2)] = 255;
+/// assert_eq!(frame, vec![0, 0, 0, 0, 0, 255]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [`StorageMut`]: trait.StorageMut.html
+pub trait StorageMut<T>: Storage<T> + DerefMut {}
+
+mod sealed {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    pub trait Sealed {}
+
+    impl<T> Sealed for Vec<T> {}
+    impl<T> Sealed for Box<[T]> {}
+    impl<T> Sealed for &[T] {}
+    impl<T> Sealed for &mut [T] {}
+    impl<T> Sealed for Rc<[T]> {}
+    impl<T> Sealed for Arc<[T]> {}
+}
+
+impl<T> Storage<T> for Vec<T> {}
+impl<T> Storage<T> for Box<[T]> {}
+impl<T> Storage<T> for &[T] {}
+impl<T> Storage<T> for &mut [T] {}
+impl<T> Storage<T> for Rc<[T]> {}
+impl<T> Storage<T> for Arc<[T]> {}
+
+impl<T> StorageMut<T> for Vec<T> {}
+impl<T> StorageMut<T> for Box<[T]> {}
+impl<T> StorageMut<T> for &mut [T] {}
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Creates a new [`Array2D`] that keeps its elements in the given
+    /// `storage`, which holds them in [row major order]. No element is copied.
+    ///
+    /// Returns an error if the number of elements in `storage` is not the
+    /// product of `num_rows` and `num_columns`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # use std::sync::Arc;
+    /// # fn main() -> Result<(), Error> {
+    /// let shared: Arc<[f32]> = Arc::from(vec![0.5, 1.0, 1.5, 2.0]);
+    /// let array = Array2D::from_storage(2, 2, Arc::clone(&shared))?;
+    /// assert_eq!(array[(1, 1)], 2.0);
+    ///
+    /// let boxed: Box<[f32]> = vec![0.0; 6].into_boxed_slice();
+    /// assert!(Array2D::from_storage(4, 2, boxed).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn from_storage(num_rows: usize, num_columns: usize, storage: S) -> Result<Self, Error> {
+        let total_len =
+            crate::total_len(num_rows, num_columns).ok_or(Error::DimensionOverflow {
+                num_rows,
+                num_columns,
+            })?;
+        if total_len != storage.len() {
+            return Err(Error::DimensionMismatch {
+                expected: total_len,
+                actual: storage.len(),
+            });
+        }
+        Ok(Array2D {
+            array: storage,
+            num_rows,
+            num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
+        })
+    }
+
+    /// Consumes the [`Array2D`] and returns the storage holding its elements,
+    /// in the order of its [`Layout`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let boxed: Box<[i32]> = vec![1, 2, 3, 4].into_boxed_slice();
+    /// let mut array = Array2D::from_storage(2, 2, boxed)?;
+    /// array[(0, 0)] = 10;
+    /// assert_eq!(&*array.into_storage(), &[10, 2, 3, 4]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`Layout`]: enum.Layout.html
+    pub fn into_storage(self) -> S {
+        self.array
+    }
+
+    /// Returns an [`Array2D`] that borrows the elements of this one.
+    pub(crate) fn borrowed(&self) -> Array2D<T, &[T]> {
+        Array2D {
+            array: &self.array,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            layout: self.layout,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Array2D<T, &'a [T]> {
+    /// Creates a new [`Array2D`] that borrows the elements of the given flat
+    /// slice in [row major order], without copying them. See
+    /// [`from_row_major`] for a version that copies the elements into a new
+    /// [`Array2D`].
+    ///
+    /// Returns an error if the number of elements in `elements` is not the
+    /// product of `num_rows` and `num_columns`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let buffer = vec![1, 2, 3, 4, 5, 6];
+    /// let array = Array2D::from_slice(2, 3, &buffer)?;
+    /// assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    /// assert!(Array2D::from_slice(4, 2, &buffer).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    /// [`from_row_major`]: struct.Array2D.html#method.from_row_major
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn from_slice(
+        num_rows: usize,
+        num_columns: usize,
+        elements: &'a [T],
+    ) -> Result<Self, Error> {
+        Array2D::from_storage(num_rows, num_columns, elements)
+    }
+
+    /// Returns a reference to the element at `row` and `column` that lives
+    /// as long as the borrowed elements. The index must be in bounds.
+    pub(crate) fn element(&self, (row, column): (usize, usize)) -> &'a T {
+        let elements: &'a [T] = self.array;
+        &elements[self.storage_index(row, column)]
+    }
+}
+
+impl<'a, T> Array2D<T, &'a mut [T]> {
+    /// Creates a new [`Array2D`] that mutably borrows the elements of the
+    /// given flat slice in [row major order], without copying them. Changes
+    /// to the array are made directly in the slice.
+    ///
+    /// Returns an error if the number of elements in `elements` is not the
+    /// product of `num_rows` and `num_columns`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut frame = vec![1, 2, 3, 4, 5, 6];
+    /// let mut array = Array2D::from_mut_slice(2, 3, &mut frame)?;
+    /// for element in array.column_iter_mut(0)? {
+    ///     *element = 0;
+    /// }
+    /// assert_eq!(frame, vec![0, 2, 3, 0, 5, 6]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn from_mut_slice(
+        num_rows: usize,
+        num_columns: usize,
+        elements: &'a mut [T],
+    ) -> Result<Self, Error> {
+        Array2D::from_storage(num_rows, num_columns, elements)
+    }
+}
@@ -0,0 +1,422 @@
+//! Orders in which to visit every element of an [`Array2D`].
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{Array2D, Error, Layout, Storage};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+/// An order in which to visit every element of an [`Array2D`].
+///
+/// Besides the usual [row major and column major orders], the elements can be
+/// visited along curves that keep consecutive elements close together in both
+/// dimensions, which can make processing that looks at nearby elements more
+/// cache friendly.
+///
+/// The [`Morton`] and [`Hilbert`] curves are defined on squares whose side is
+/// a power of two. Other arrays are covered by a row or column of such
+/// squares, each as large as the shorter side of the array rounded up to a
+/// power of two, and the elements of the curve outside the array are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::{Array2D, Traversal};
+/// let array = Array2D::filled_with(2, 2, 0);
+/// let indices = |traversal| array.indices(traversal).collect::<Vec<_>>();
+/// assert_eq!(indices(Traversal::RowMajor), vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+/// assert_eq!(indices(Traversal::ColumnMajor), vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+/// assert_eq!(indices(Traversal::Snake), vec![(0, 0), (0, 1), (1, 1), (1, 0)]);
+/// assert_eq!(indices(Traversal::Morton), vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+/// assert_eq!(indices(Traversal::Hilbert), vec![(0, 0), (1, 0), (1, 1), (0, 1)]);
+/// ```
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [row major and column major orders]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+/// [`Morton`]: enum.Traversal.html#variant.Morton
+/// [`Hilbert`]: enum.Traversal.html#variant.Hilbert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Traversal {
+    /// Each row in turn, from left to right.
+    RowMajor,
+    /// Each column in turn, from top to bottom.
+    ColumnMajor,
+    /// Each row in turn, alternating between left to right and right to left,
+    /// also known as [boustrophedon] order.
+    ///
+    /// [boustrophedon]: https://en.wikipedia.org/wiki/Boustrophedon
+    Snake,
+    /// The [Z-order curve], also known as Morton order.
+    ///
+    /// [Z-order curve]: https://en.wikipedia.org/wiki/Z-order_curve
+    Morton,
+    /// The [Hilbert curve], starting at the top left corner.
+    ///
+    /// [Hilbert curve]: https://en.wikipedia.org/wiki/Hilbert_curve
+    Hilbert,
+}
+
+impl<T> Array2D<T> {
+    /// Creates a new [`Array2D`] with the specified number of rows and columns
+    /// and fills each element with the elements produced from the provided
+    /// iterator, in the order given by `traversal`. If the iterator produces
+    /// more than enough elements, the remaining are unused. Returns an error if
+    /// the iterator does not produce enough elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error, Traversal};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_iter(3, 3, Traversal::Snake, 1..)?;
+    /// assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![6, 5, 4], vec![7, 8, 9]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn from_iter<I>(
+        num_rows: usize,
+        num_columns: usize,
+        traversal: Traversal,
+        iterator: I,
+    ) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iterator = iterator.into_iter();
+        if traversal == Traversal::RowMajor {
+            return Array2D::from_iter_row_major(num_rows, num_columns, iterator);
+        }
+        let total_len =
+            crate::total_len(num_rows, num_columns).ok_or(Error::DimensionOverflow {
+                num_rows,
+                num_columns,
+            })?;
+        let mut slots = Vec::new();
+        slots.resize_with(total_len, || None);
+        let indices = Indices::new(num_rows, num_columns, traversal);
+        let mut filled = 0;
+        for ((row, column), element) in indices.zip(iterator) {
+            slots[row * num_columns + column] = Some(element);
+            filled += 1;
+        }
+        if filled != total_len {
+            return Err(Error::NotEnoughElements {
+                expected: total_len,
+                actual: filled,
+            });
+        }
+        Ok(Array2D {
+            array: slots.into_iter().map(Option::unwrap).collect(),
+            num_rows,
+            num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Creates a new [`Array2D`] from an existing [`Array2D`] by calling
+    /// `mapper` on each element, visiting the elements in the order given by
+    /// `traversal`. The new element is placed at the same index as the
+    /// element it was produced from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error, Traversal};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::filled_with(2, 2, 0);
+    /// let mut counter = 0;
+    /// let visit_order = array.map(Traversal::Hilbert, |_| {
+    ///     counter += 1;
+    ///     counter
+    /// });
+    /// assert_eq!(visit_order.as_rows(), vec![vec![1, 4], vec![2, 3]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn map<F, U>(&self, traversal: Traversal, mut mapper: F) -> Array2D<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let elements = self
+            .enumerate(traversal)
+            .map(|(_, element)| mapper(element));
+        Array2D::from_iter(self.num_rows, self.num_columns, traversal, elements)
+            .expect("enumerate should produce every element of the array")
+    }
+
+    /// Returns an [`Iterator`] over the indices of the array in the order
+    /// given by `traversal`. Each index is a tuple of [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Traversal};
+    /// let array = Array2D::filled_with(2, 3, 0);
+    /// let indices = array.indices(Traversal::Snake).collect::<Vec<_>>();
+    /// assert_eq!(indices, vec![(0, 0), (0, 1), (0, 2), (1, 2), (1, 1), (1, 0)]);
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    pub fn indices(&self, traversal: Traversal) -> Indices {
+        Indices::new(self.num_rows, self.num_columns, traversal)
+    }
+
+    /// Iterate through the array in the order given by `traversal` along with
+    /// the corresponding indices. Each index is a tuple of [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error, Traversal};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2], vec![3, 4]])?;
+    /// let enumerated = array.enumerate(Traversal::Morton).collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     enumerated,
+    ///     vec![((0, 0), &1), ((0, 1), &2), ((1, 0), &3), ((1, 1), &4)]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    pub fn enumerate(&self, traversal: Traversal) -> Enumerate<'_, T> {
+        Enumerate {
+            indices: self.indices(traversal),
+            array: self.borrowed(),
+        }
+    }
+}
+
+/// An [`Iterator`] over the indices of an [`Array2D`] in the order given by a
+/// [`Traversal`], created by [`Array2D::indices`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Traversal`]: enum.Traversal.html
+/// [`Array2D::indices`]: struct.Array2D.html#method.indices
+#[derive(Debug, Clone)]
+pub struct Indices {
+    curve: Curve,
+    front: usize,
+    back: usize,
+    remaining: usize,
+}
+
+impl Indices {
+    fn new(num_rows: usize, num_columns: usize, traversal: Traversal) -> Self {
+        let curve = Curve::new(num_rows, num_columns, traversal);
+        Indices {
+            front: 0,
+            back: curve.len(),
+            remaining: num_rows * num_columns,
+            curve,
+        }
+    }
+}
+
+impl Iterator for Indices {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            self.front += 1;
+            if let Some(index) = self.curve.get(self.front - 1) {
+                self.remaining -= 1;
+                return Some(index);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for Indices {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            self.back -= 1;
+            if let Some(index) = self.curve.get(self.back) {
+                self.remaining -= 1;
+                return Some(index);
+            }
+        }
+        None
+    }
+}
+
+impl ExactSizeIterator for Indices {}
+
+impl FusedIterator for Indices {}
+
+/// An [`Iterator`] over all elements of an [`Array2D`] in the order given by a
+/// [`Traversal`] along with their indices, created by [`Array2D::enumerate`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Traversal`]: enum.Traversal.html
+/// [`Array2D::enumerate`]: struct.Array2D.html#method.enumerate
+#[derive(Debug)]
+pub struct Enumerate<'a, T> {
+    indices: Indices,
+    array: Array2D<T, &'a [T]>,
+}
+
+impl<T> Clone for Enumerate<'_, T> {
+    fn clone(&self) -> Self {
+        Enumerate {
+            indices: self.indices.clone(),
+            array: self.array,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Enumerate<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        Some((index, self.array.element(index)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Enumerate<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_back()?;
+        Some((index, self.array.element(index)))
+    }
+}
+
+impl<T> ExactSizeIterator for Enumerate<'_, T> {}
+
+impl<T> FusedIterator for Enumerate<'_, T> {}
+
+/// A [`Traversal`] of an array with particular dimensions, mapping positions
+/// along it to indices.
+///
+/// [`Traversal`]: enum.Traversal.html
+#[derive(Debug, Clone)]
+struct Curve {
+    traversal: Traversal,
+    num_rows: usize,
+    num_columns: usize,
+    /// The side of the squares that the Morton and Hilbert curves are laid
+    /// out on.
+    side: usize,
+}
+
+impl Curve {
+    fn new(num_rows: usize, num_columns: usize, traversal: Traversal) -> Self {
+        Curve {
+            traversal,
+            num_rows,
+            num_columns,
+            side: num_rows.min(num_columns).next_power_of_two(),
+        }
+    }
+
+    /// The number of positions along the curve, including those outside the
+    /// array.
+    fn len(&self) -> usize {
+        let len = self.num_rows * self.num_columns;
+        match self.traversal {
+            _ if len == 0 => 0,
+            Traversal::RowMajor | Traversal::ColumnMajor | Traversal::Snake => len,
+            Traversal::Morton | Traversal::Hilbert => {
+                let long_side = self.num_rows.max(self.num_columns);
+                self.side * self.side * long_side.div_ceil(self.side)
+            }
+        }
+    }
+
+    /// The index at the given position along the curve, if it is inside the
+    /// array.
+    fn get(&self, position: usize) -> Option<(usize, usize)> {
+        let (num_rows, num_columns) = (self.num_rows, self.num_columns);
+        let (row, column) = match self.traversal {
+            Traversal::RowMajor => (position / num_columns, position % num_columns),
+            Traversal::ColumnMajor => (position % num_rows, position / num_rows),
+            Traversal::Snake => {
+                let (row, offset) = (position / num_columns, position % num_columns);
+                if row % 2 == 0 {
+                    (row, offset)
+                } else {
+                    (row, num_columns - 1 - offset)
+                }
+            }
+            Traversal::Morton | Traversal::Hilbert => {
+                let square_len = self.side * self.side;
+                let (square, position) = (position / square_len, position % square_len);
+                let (along, across) = if self.traversal == Traversal::Morton {
+                    morton(position)
+                } else {
+                    hilbert(self.side, position)
+                };
+                // The squares are laid out along the longer side of the
+                // array.
+                let along = square * self.side + along;
+                if num_rows > num_columns {
+                    (along, across)
+                } else {
+                    (across, along)
+                }
+            }
+        };
+        (row < num_rows && column < num_columns).then_some((row, column))
+    }
+}
+
+/// The coordinates of the given position along the Z-order curve, as
+/// `(x, y)` where `x` comes from the even bits of the position and `y` from
+/// the odd bits.
+fn morton(position: usize) -> (usize, usize) {
+    let mut x = 0;
+    let mut y = 0;
+    let mut bit = 0;
+    while position >> (2 * bit) != 0 {
+        x |= (position >> (2 * bit) & 1) << bit;
+        y |= (position >> (2 * bit + 1) & 1) << bit;
+        bit += 1;
+    }
+    (x, y)
+}
+
+/// The coordinates of the given position along the Hilbert curve filling a
+/// square with the given side, which must be a power of two. The curve starts
+/// at `(0, 0)` and ends at `(side - 1, 0)`.
+fn hilbert(side: usize, position: usize) -> (usize, usize) {
+    let mut x = 0;
+    let mut y = 0;
+    let mut remaining = position;
+    let mut size = 1;
+    while size < side {
+        let rx = 1 & (remaining / 2);
+        let ry = 1 & (remaining ^ rx);
+        if ry == 0 {
+            if rx == 1 {
+                x = size - 1 - x;
+                y = size - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        x += size * rx;
+        y += size * ry;
+        remaining /= 4;
+        size *= 2;
+    }
+    (x, y)
+}
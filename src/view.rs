@@ -0,0 +1,1063 @@
+//! Borrowed views into a rectangular region of an [`Array2D`].
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{
+    Array2D, ColumnIter, ColumnsIter, Error, Index2D, Layout, RowIter, RowsIter, Storage,
+    StorageMut,
+};
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+
+/// A read-only view into a rectangular region of an [`Array2D`].
+///
+/// A view borrows the elements of the array it was created from, so creating
+/// one never copies any elements. Use [`to_owned`] to copy the elements into a
+/// new [`Array2D`].
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [`to_owned`]: struct.ArrayView.html#method.to_owned
+pub struct ArrayView<'a, T> {
+    ptr: *const T,
+    num_rows: usize,
+    num_columns: usize,
+    row_stride: usize,
+    column_stride: usize,
+    marker: PhantomData<&'a T>,
+}
+
+/// A mutable view into a rectangular region of an [`Array2D`].
+///
+/// Several mutable views into disjoint regions of the same array can exist at
+/// the same time (see [`chunks_mut`]).
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [`chunks_mut`]: struct.Array2D.html#method.chunks_mut
+pub struct ArrayViewMut<'a, T> {
+    ptr: *mut T,
+    num_rows: usize,
+    num_columns: usize,
+    row_stride: usize,
+    column_stride: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: An `ArrayView` behaves like a `&'a [T]`.
+unsafe impl<T: Sync> Send for ArrayView<'_, T> {}
+unsafe impl<T: Sync> Sync for ArrayView<'_, T> {}
+
+// SAFETY: An `ArrayViewMut` behaves like a `&'a mut [T]`.
+unsafe impl<T: Send> Send for ArrayViewMut<'_, T> {}
+unsafe impl<T: Sync> Sync for ArrayViewMut<'_, T> {}
+
+impl<T> Clone for ArrayView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ArrayView<'_, T> {}
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns a read-only [`ArrayView`] of the whole array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let array = Array2D::from_rows(&rows)?;
+    /// let view = array.view();
+    /// assert_eq!(view.get((1, 2)), Some(&6));
+    /// assert_eq!(view.to_owned(), array);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ArrayView`]: struct.ArrayView.html
+    pub fn view(&self) -> ArrayView<'_, T> {
+        let (row_stride, column_stride) = self.strides();
+        // SAFETY: The strides describe exactly the elements of `self.array`.
+        unsafe {
+            ArrayView::new(
+                self.array.as_ptr(),
+                self.num_rows,
+                self.num_columns,
+                row_stride,
+                column_stride,
+            )
+        }
+    }
+
+    /// Returns an [`ArrayViewMut`] of the whole array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(2, 3, 0);
+    /// let mut view = array.view_mut();
+    /// view[(1, 2)] = 6;
+    /// assert_eq!(array.as_rows(), vec![vec![0, 0, 0], vec![0, 0, 6]]);
+    /// ```
+    ///
+    /// [`ArrayViewMut`]: struct.ArrayViewMut.html
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        let (row_stride, column_stride) = self.strides();
+        // SAFETY: The strides describe exactly the elements of `self.array`.
+        unsafe {
+            ArrayViewMut::new(
+                self.array.as_mut_ptr(),
+                self.num_rows,
+                self.num_columns,
+                row_stride,
+                column_stride,
+            )
+        }
+    }
+
+    /// Returns a read-only [`ArrayView`] of every `row_step`-th row and every
+    /// `column_step`-th column of the array, starting with the first row and
+    /// column. See [`step_by_offset`] to start somewhere else.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_step` or `column_step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_iter_row_major(4, 6, 0..)?;
+    /// let downsampled = array.step_by(2, 3);
+    /// assert_eq!(downsampled.to_owned().as_rows(), vec![vec![0, 3], vec![12, 15]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ArrayView`]: struct.ArrayView.html
+    /// [`step_by_offset`]: struct.Array2D.html#method.step_by_offset
+    pub fn step_by(&self, row_step: usize, column_step: usize) -> ArrayView<'_, T> {
+        self.view().step_by(row_step, column_step)
+    }
+
+    /// Returns a read-only [`ArrayView`] of every `row_step`-th row and every
+    /// `column_step`-th column of the array, starting at the row and column
+    /// given by `offset`, given as `(row, column)`. The view has no rows (or
+    /// no columns) when that coordinate of the offset is out of bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_step` or `column_step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// // Two rows of interleaved RGB pixels.
+    /// let rgb = vec![1, 10, 100, 2, 20, 200, 3, 30, 300, 4, 40, 400];
+    /// let array = Array2D::from_row_major(2, 6, &rgb)?;
+    /// let green = array.step_by_offset((0, 1), 1, 3);
+    /// assert_eq!(green.to_owned().as_rows(), vec![vec![10, 20], vec![30, 40]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ArrayView`]: struct.ArrayView.html
+    pub fn step_by_offset(
+        &self,
+        offset: (usize, usize),
+        row_step: usize,
+        column_step: usize,
+    ) -> ArrayView<'_, T> {
+        self.view().step_by_offset(offset, row_step, column_step)
+    }
+
+    /// Returns an [`ArrayViewMut`] of every `row_step`-th row and every
+    /// `column_step`-th column of the array. This is the mutable version of
+    /// [`step_by`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_step` or `column_step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(3, 3, 0);
+    /// for element in array.step_by_mut(2, 2).elements_row_major_iter_mut() {
+    ///     *element = 1;
+    /// }
+    /// assert_eq!(
+    ///     array.as_rows(),
+    ///     vec![vec![1, 0, 1], vec![0, 0, 0], vec![1, 0, 1]]
+    /// );
+    /// ```
+    ///
+    /// [`ArrayViewMut`]: struct.ArrayViewMut.html
+    /// [`step_by`]: struct.Array2D.html#method.step_by
+    pub fn step_by_mut(&mut self, row_step: usize, column_step: usize) -> ArrayViewMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        self.view_mut()
+            .into_step_by_offset((0, 0), row_step, column_step)
+    }
+
+    /// Returns an [`ArrayViewMut`] of every `row_step`-th row and every
+    /// `column_step`-th column of the array, starting at the row and column
+    /// given by `offset`. This is the mutable version of [`step_by_offset`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_step` or `column_step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(2, 4, 0);
+    /// array.step_by_offset_mut((1, 1), 1, 2).set((0, 1), 5).unwrap();
+    /// assert_eq!(array.as_rows(), vec![vec![0, 0, 0, 0], vec![0, 0, 0, 5]]);
+    /// ```
+    ///
+    /// [`ArrayViewMut`]: struct.ArrayViewMut.html
+    /// [`step_by_offset`]: struct.Array2D.html#method.step_by_offset
+    pub fn step_by_offset_mut(
+        &mut self,
+        offset: (usize, usize),
+        row_step: usize,
+        column_step: usize,
+    ) -> ArrayViewMut<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        self.view_mut()
+            .into_step_by_offset(offset, row_step, column_step)
+    }
+
+    /// Returns a read-only [`ArrayView`] of the rows and columns in the given
+    /// ranges, given as `(rows, columns)`. Each range can be any kind of
+    /// range of `usize`, such as `1..3`, `2..`, `..=1` or `..`. Returns an
+    /// error if either range is decreasing or extends past the end of the
+    /// array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_iter_row_major(3, 4, 0..)?;
+    /// let middle = array.slice((1..3, ..))?;
+    /// assert_eq!(middle.to_owned().as_rows(), vec![vec![4, 5, 6, 7], vec![8, 9, 10, 11]]);
+    /// let corner = array.slice((..=1, 2..))?;
+    /// assert_eq!(corner.to_owned().as_rows(), vec![vec![2, 3], vec![6, 7]]);
+    /// assert_eq!(
+    ///     array.slice((2..5, ..)).err(),
+    ///     Some(Error::RowRangeOutOfBounds {
+    ///         start: 2,
+    ///         end: 5,
+    ///         num_rows: 3
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ArrayView`]: struct.ArrayView.html
+    pub fn slice<R, C>(&self, ranges: (R, C)) -> Result<ArrayView<'_, T>, Error>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        self.view().slice(ranges)
+    }
+
+    /// Returns an [`ArrayViewMut`] of the rows and columns in the given
+    /// ranges, given as `(rows, columns)`. This is the mutable version of
+    /// [`slice`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut array = Array2D::filled_with(2, 4, 0);
+    /// for element in array.slice_mut((.., 2..))?.elements_row_major_iter_mut() {
+    ///     *element = 1;
+    /// }
+    /// assert_eq!(array.as_rows(), vec![vec![0, 0, 1, 1], vec![0, 0, 1, 1]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ArrayViewMut`]: struct.ArrayViewMut.html
+    /// [`slice`]: struct.Array2D.html#method.slice
+    pub fn slice_mut<R, C>(&mut self, ranges: (R, C)) -> Result<ArrayViewMut<'_, T>, Error>
+    where
+        S: StorageMut<T>,
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        self.view_mut().into_slice(ranges)
+    }
+}
+
+impl<'a, T> ArrayView<'a, T> {
+    /// # Safety
+    ///
+    /// For every `row < num_rows` and `column < num_columns`, `ptr + row *
+    /// row_stride + column * column_stride` must point to an element that is
+    /// valid for reads for `'a`.
+    pub(crate) unsafe fn new(
+        ptr: *const T,
+        num_rows: usize,
+        num_columns: usize,
+        row_stride: usize,
+        column_stride: usize,
+    ) -> Self {
+        ArrayView {
+            ptr,
+            num_rows,
+            num_columns,
+            row_stride,
+            column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// The number of rows.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns.
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// The total number of elements, i.e. the product of `num_rows` and
+    /// `num_columns`.
+    pub fn num_elements(&self) -> usize {
+        self.num_rows * self.num_columns
+    }
+
+    /// Returns a reference to the element at the given [`Index2D`] of the
+    /// view if the index is in bounds (wrapped in [`Some`]). Returns [`None`]
+    /// if the index is out of bounds.
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get<I: Index2D>(&self, index: I) -> Option<&'a T> {
+        let (row, column) = index.to_row_column();
+        if row < self.num_rows && column < self.num_columns {
+            // SAFETY: The indices were just checked.
+            Some(unsafe { self.get_unchecked(row, column) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an [`Iterator`] over references to all elements in the given
+    /// row of the view. Returns an error if the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn row_iter(&self, row_index: usize) -> Result<RowIter<'a, T>, Error> {
+        if row_index >= self.num_rows {
+            return Err(Error::RowOutOfBounds {
+                row: row_index,
+                num_rows: self.num_rows,
+            });
+        }
+        // SAFETY: The row is in bounds. An empty row is never offset, since
+        // it may not have a valid start.
+        Ok(unsafe {
+            let start = if self.num_columns == 0 {
+                self.ptr
+            } else {
+                self.ptr.add(row_index * self.row_stride)
+            };
+            RowIter::new(start, self.num_columns, self.column_stride)
+        })
+    }
+
+    /// Returns an [`Iterator`] over references to all elements in the given
+    /// column of the view. Returns an error if the index is out of bounds.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    pub fn column_iter(&self, column_index: usize) -> Result<ColumnIter<'a, T>, Error> {
+        if column_index >= self.num_columns {
+            return Err(Error::ColumnOutOfBounds {
+                column: column_index,
+                num_columns: self.num_columns,
+            });
+        }
+        // SAFETY: The column is in bounds. An empty column is never offset,
+        // since it may not have a valid start.
+        Ok(unsafe {
+            let start = if self.num_rows == 0 {
+                self.ptr
+            } else {
+                self.ptr.add(column_index * self.column_stride)
+            };
+            ColumnIter::new(start, self.num_rows, self.row_stride)
+        })
+    }
+
+    /// Returns an [`Iterator`] over all rows of the view. Each [`Item`] is
+    /// itself another [`Iterator`] over references to the elements in that
+    /// row.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    pub fn rows_iter(&self) -> RowsIter<'a, T> {
+        // SAFETY: The view's strides describe exactly its own elements.
+        unsafe {
+            RowsIter::new(
+                self.ptr,
+                self.num_rows,
+                self.row_stride,
+                self.num_columns,
+                self.column_stride,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over all columns of the view. Each [`Item`] is
+    /// itself another [`Iterator`] over references to the elements in that
+    /// column.
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    pub fn columns_iter(&self) -> ColumnsIter<'a, T> {
+        // SAFETY: The view's strides describe exactly its own elements.
+        unsafe {
+            ColumnsIter::new(
+                self.ptr,
+                self.num_columns,
+                self.column_stride,
+                self.num_rows,
+                self.row_stride,
+            )
+        }
+    }
+
+    /// Returns an [`Iterator`] over references to all elements of the view in
+    /// [row major order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_row_major_iter(&self) -> impl DoubleEndedIterator<Item = &'a T> + Clone {
+        let view = *self;
+        // SAFETY: All indices come from the view's dimensions.
+        crate::indices_row_major(self.num_rows, self.num_columns)
+            .map(move |(row, column)| unsafe { view.get_unchecked(row, column) })
+    }
+
+    /// Returns an [`Iterator`] over references to all elements of the view in
+    /// [column major order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [column major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_column_major_iter(&self) -> impl DoubleEndedIterator<Item = &'a T> + Clone {
+        let view = *self;
+        // SAFETY: All indices come from the view's dimensions.
+        crate::indices_column_major(self.num_rows, self.num_columns)
+            .map(move |(row, column)| unsafe { view.get_unchecked(row, column) })
+    }
+
+    /// Iterate through the view in row major order along with the
+    /// corresponding indices, relative to the view. Each index is a tuple of
+    /// [`usize`].
+    ///
+    /// [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
+    pub fn enumerate_row_major(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = ((usize, usize), &'a T)> + Clone {
+        let view = *self;
+        // SAFETY: All indices come from the view's dimensions.
+        crate::indices_row_major(self.num_rows, self.num_columns)
+            .map(move |(row, column)| ((row, column), unsafe { view.get_unchecked(row, column) }))
+    }
+
+    /// Returns a view of every `row_step`-th row and every `column_step`-th
+    /// column of this view. See [`Array2D::step_by`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_step` or `column_step` is zero.
+    ///
+    /// [`Array2D::step_by`]: struct.Array2D.html#method.step_by
+    pub fn step_by(&self, row_step: usize, column_step: usize) -> ArrayView<'a, T> {
+        self.step_by_offset((0, 0), row_step, column_step)
+    }
+
+    /// Returns a view of every `row_step`-th row and every `column_step`-th
+    /// column of this view, starting at `offset`. See
+    /// [`Array2D::step_by_offset`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_step` or `column_step` is zero.
+    ///
+    /// [`Array2D::step_by_offset`]: struct.Array2D.html#method.step_by_offset
+    pub fn step_by_offset(
+        &self,
+        offset: (usize, usize),
+        row_step: usize,
+        column_step: usize,
+    ) -> ArrayView<'a, T> {
+        let strided = Strided::new(
+            self.num_rows,
+            self.num_columns,
+            offset,
+            row_step,
+            column_step,
+        );
+        ArrayView {
+            // SAFETY: `start` is only non-zero when it is in bounds.
+            ptr: unsafe {
+                self.ptr
+                    .add(strided.start(self.row_stride, self.column_stride))
+            },
+            num_rows: strided.num_rows,
+            num_columns: strided.num_columns,
+            // A stride can only overflow when its step is so large that a
+            // single row or column is selected, so it is never used.
+            row_stride: self.row_stride.saturating_mul(row_step),
+            column_stride: self.column_stride.saturating_mul(column_step),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a view of the rows and columns of this view in the given
+    /// ranges, given as `(rows, columns)`. See [`Array2D::slice`].
+    ///
+    /// [`Array2D::slice`]: struct.Array2D.html#method.slice
+    pub fn slice<R, C>(&self, (rows, columns): (R, C)) -> Result<ArrayView<'a, T>, Error>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (row, column, num_rows, num_columns) =
+            slice_region(&rows, &columns, self.num_rows, self.num_columns)?;
+        Ok(self.subview(row, column, num_rows, num_columns))
+    }
+
+    /// Copies the elements of the view into a new [`Array2D`].
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn to_owned(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        Array2D {
+            array: self.elements_row_major_iter().cloned().collect(),
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the sub-view with the given top-left corner and dimensions.
+    /// The region must lie within the view.
+    pub(crate) fn subview(
+        &self,
+        row: usize,
+        column: usize,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> ArrayView<'a, T> {
+        debug_assert!(row + num_rows <= self.num_rows);
+        debug_assert!(column + num_columns <= self.num_columns);
+        let ptr = if num_rows == 0 || num_columns == 0 {
+            self.ptr
+        } else {
+            // SAFETY: The corner is in bounds because the region is non-empty.
+            unsafe {
+                self.ptr
+                    .add(row * self.row_stride + column * self.column_stride)
+            }
+        };
+        ArrayView {
+            ptr,
+            num_rows,
+            num_columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `row` and `column` must be in bounds.
+    unsafe fn get_unchecked(&self, row: usize, column: usize) -> &'a T {
+        &*self
+            .ptr
+            .add(row * self.row_stride + column * self.column_stride)
+    }
+}
+
+impl<'a, T> ArrayViewMut<'a, T> {
+    /// # Safety
+    ///
+    /// For every `row < num_rows` and `column < num_columns`, `ptr + row *
+    /// row_stride + column * column_stride` must point to a distinct element
+    /// that is valid for reads and writes, and not accessed through any other
+    /// pointer, for `'a`.
+    pub(crate) unsafe fn new(
+        ptr: *mut T,
+        num_rows: usize,
+        num_columns: usize,
+        row_stride: usize,
+        column_stride: usize,
+    ) -> Self {
+        ArrayViewMut {
+            ptr,
+            num_rows,
+            num_columns,
+            row_stride,
+            column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// The number of rows.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// The number of columns.
+    pub fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    /// The total number of elements, i.e. the product of `num_rows` and
+    /// `num_columns`.
+    pub fn num_elements(&self) -> usize {
+        self.num_rows * self.num_columns
+    }
+
+    /// Returns a read-only [`ArrayView`] of the same region.
+    ///
+    /// [`ArrayView`]: struct.ArrayView.html
+    pub fn view(&self) -> ArrayView<'_, T> {
+        ArrayView {
+            ptr: self.ptr,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts this mutable view into a read-only [`ArrayView`] with the same
+    /// lifetime.
+    ///
+    /// [`ArrayView`]: struct.ArrayView.html
+    pub fn into_view(self) -> ArrayView<'a, T> {
+        ArrayView {
+            ptr: self.ptr,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the element at the given [`Index2D`] of the
+    /// view if the index is in bounds (wrapped in [`Some`]). Returns [`None`]
+    /// if the index is out of bounds.
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get<I: Index2D>(&self, index: I) -> Option<&T> {
+        self.view().get(index)
+    }
+
+    /// Returns a mutable reference to the element at the given [`Index2D`]
+    /// of the view if the index is in bounds (wrapped in [`Some`]). Returns
+    /// [`None`] if the index is out of bounds.
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    /// [`Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut<I: Index2D>(&mut self, index: I) -> Option<&mut T> {
+        let (row, column) = index.to_row_column();
+        if row < self.num_rows && column < self.num_columns {
+            // SAFETY: The indices were just checked.
+            Some(unsafe { &mut *self.ptr_at(row, column) })
+        } else {
+            None
+        }
+    }
+
+    /// Changes the element at the given [`Index2D`] of the view to `element`.
+    /// Returns [`Ok(())`] if the indices were in bounds and returns an
+    /// [`Err`] otherwise.
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    /// [`Ok(())`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Ok
+    /// [`Err`]: https://doc.rust-lang.org/std/result/enum.Result.html#variant.Err
+    pub fn set<I: Index2D>(&mut self, index: I, element: T) -> Result<(), Error> {
+        let (row, column) = index.to_row_column();
+        self.get_mut((row, column))
+            .map(|location| {
+                *location = element;
+            })
+            .ok_or(Error::IndicesOutOfBounds {
+                row,
+                column,
+                num_rows: self.num_rows,
+                num_columns: self.num_columns,
+            })
+    }
+
+    /// Returns an [`Iterator`] over references to all elements of the view in
+    /// [row major order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_row_major_iter(&self) -> impl DoubleEndedIterator<Item = &T> + Clone {
+        self.view().elements_row_major_iter()
+    }
+
+    /// Returns an [`Iterator`] over mutable references to all elements of the
+    /// view in [row major order].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn elements_row_major_iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        let (ptr, row_stride, column_stride) = (self.ptr, self.row_stride, self.column_stride);
+        // SAFETY: Every index is in bounds and is produced exactly once, so
+        // the mutable references never alias.
+        crate::indices_row_major(self.num_rows, self.num_columns).map(move |(row, column)| unsafe {
+            &mut *ptr.add(row * row_stride + column * column_stride)
+        })
+    }
+
+    /// Returns a mutable view of every `row_step`-th row and every
+    /// `column_step`-th column of this view. See [`Array2D::step_by_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_step` or `column_step` is zero.
+    ///
+    /// [`Array2D::step_by_mut`]: struct.Array2D.html#method.step_by_mut
+    pub fn step_by_mut(&mut self, row_step: usize, column_step: usize) -> ArrayViewMut<'_, T> {
+        self.step_by_offset_mut((0, 0), row_step, column_step)
+    }
+
+    /// Returns a mutable view of every `row_step`-th row and every
+    /// `column_step`-th column of this view, starting at `offset`. See
+    /// [`Array2D::step_by_offset_mut`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_step` or `column_step` is zero.
+    ///
+    /// [`Array2D::step_by_offset_mut`]: struct.Array2D.html#method.step_by_offset_mut
+    pub fn step_by_offset_mut(
+        &mut self,
+        offset: (usize, usize),
+        row_step: usize,
+        column_step: usize,
+    ) -> ArrayViewMut<'_, T> {
+        self.reborrow()
+            .into_step_by_offset(offset, row_step, column_step)
+    }
+
+    /// Returns a read-only view of the rows and columns of this view in the
+    /// given ranges, given as `(rows, columns)`. See [`Array2D::slice`].
+    ///
+    /// [`Array2D::slice`]: struct.Array2D.html#method.slice
+    pub fn slice<R, C>(&self, ranges: (R, C)) -> Result<ArrayView<'_, T>, Error>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        self.view().slice(ranges)
+    }
+
+    /// Returns a mutable view of the rows and columns of this view in the
+    /// given ranges, given as `(rows, columns)`. See [`Array2D::slice_mut`].
+    ///
+    /// [`Array2D::slice_mut`]: struct.Array2D.html#method.slice_mut
+    pub fn slice_mut<R, C>(&mut self, ranges: (R, C)) -> Result<ArrayViewMut<'_, T>, Error>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        self.reborrow().into_slice(ranges)
+    }
+
+    /// Copies the elements of the view into a new [`Array2D`].
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    pub fn to_owned(&self) -> Array2D<T>
+    where
+        T: Clone,
+    {
+        self.view().to_owned()
+    }
+
+    fn reborrow(&mut self) -> ArrayViewMut<'_, T> {
+        ArrayViewMut {
+            ptr: self.ptr,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    fn into_step_by_offset(
+        self,
+        offset: (usize, usize),
+        row_step: usize,
+        column_step: usize,
+    ) -> ArrayViewMut<'a, T> {
+        let strided = Strided::new(
+            self.num_rows,
+            self.num_columns,
+            offset,
+            row_step,
+            column_step,
+        );
+        ArrayViewMut {
+            // SAFETY: `start` is only non-zero when it is in bounds.
+            ptr: unsafe {
+                self.ptr
+                    .add(strided.start(self.row_stride, self.column_stride))
+            },
+            num_rows: strided.num_rows,
+            num_columns: strided.num_columns,
+            // A stride can only overflow when its step is so large that a
+            // single row or column is selected, so it is never used.
+            row_stride: self.row_stride.saturating_mul(row_step),
+            column_stride: self.column_stride.saturating_mul(column_step),
+            marker: PhantomData,
+        }
+    }
+
+    fn into_slice<R, C>(self, (rows, columns): (R, C)) -> Result<ArrayViewMut<'a, T>, Error>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (row, column, num_rows, num_columns) =
+            slice_region(&rows, &columns, self.num_rows, self.num_columns)?;
+        // SAFETY: `self` is consumed, so the sub-view is the only view left.
+        Ok(unsafe { self.subview_unchecked(row, column, num_rows, num_columns) })
+    }
+
+    /// Returns the mutable sub-view with the given top-left corner and
+    /// dimensions. The region must lie within the view.
+    ///
+    /// # Safety
+    ///
+    /// The returned view must not overlap with any other live view created
+    /// from `self`.
+    pub(crate) unsafe fn subview_unchecked(
+        &self,
+        row: usize,
+        column: usize,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> ArrayViewMut<'a, T> {
+        debug_assert!(row + num_rows <= self.num_rows);
+        debug_assert!(column + num_columns <= self.num_columns);
+        let ptr = if num_rows == 0 || num_columns == 0 {
+            self.ptr
+        } else {
+            self.ptr_at(row, column)
+        };
+        ArrayViewMut {
+            ptr,
+            num_rows,
+            num_columns,
+            row_stride: self.row_stride,
+            column_stride: self.column_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `row` and `column` must be in bounds.
+    unsafe fn ptr_at(&self, row: usize, column: usize) -> *mut T {
+        self.ptr
+            .add(row * self.row_stride + column * self.column_stride)
+    }
+}
+
+impl<T, I: Index2D> Index<I> for ArrayView<'_, T> {
+    type Output = T;
+
+    /// Returns the element at the given index, given as any [`Index2D`] such
+    /// as `(row, column)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    fn index(&self, index: I) -> &Self::Output {
+        let (row, column) = index.to_row_column();
+        self.get((row, column))
+            .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
+    }
+}
+
+impl<T, I: Index2D> Index<I> for ArrayViewMut<'_, T> {
+    type Output = T;
+
+    /// Returns the element at the given index, given as any [`Index2D`] such
+    /// as `(row, column)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    fn index(&self, index: I) -> &Self::Output {
+        let (row, column) = index.to_row_column();
+        self.get((row, column))
+            .unwrap_or_else(|| panic!("Index indices {}, {} out of bounds", row, column))
+    }
+}
+
+impl<T, I: Index2D> IndexMut<I> for ArrayViewMut<'_, T> {
+    /// Returns a mutable version of the element at the given index, given as
+    /// any [`Index2D`] such as `(row, column)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are out of bounds.
+    ///
+    /// [`Index2D`]: trait.Index2D.html
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let (row, column) = index.to_row_column();
+        self.get_mut((row, column))
+            .unwrap_or_else(|| panic!("Index mut indices {}, {} out of bounds", row, column))
+    }
+}
+
+impl<T: Debug> Debug for ArrayView<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.rows_iter().map(|row| row.collect::<Vec<_>>()))
+            .finish()
+    }
+}
+
+impl<T: Debug> Debug for ArrayViewMut<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.view().fmt(f)
+    }
+}
+
+/// Resolves the ranges of `rows` and `columns` against a view with the given
+/// dimensions, returning the top-left corner and the dimensions of the region
+/// they select.
+fn slice_region<R, C>(
+    rows: &R,
+    columns: &C,
+    num_rows: usize,
+    num_columns: usize,
+) -> Result<(usize, usize, usize, usize), Error>
+where
+    R: RangeBounds<usize>,
+    C: RangeBounds<usize>,
+{
+    let (row_start, row_end) = range_bounds(rows, num_rows);
+    if row_start > row_end || row_end > num_rows {
+        return Err(Error::RowRangeOutOfBounds {
+            start: row_start,
+            end: row_end,
+            num_rows,
+        });
+    }
+    let (column_start, column_end) = range_bounds(columns, num_columns);
+    if column_start > column_end || column_end > num_columns {
+        return Err(Error::ColumnRangeOutOfBounds {
+            start: column_start,
+            end: column_end,
+            num_columns,
+        });
+    }
+    Ok((
+        row_start,
+        column_start,
+        row_end - row_start,
+        column_end - column_start,
+    ))
+}
+
+/// The start and exclusive end of `range` on an axis of length `len`.
+fn range_bounds<R: RangeBounds<usize>>(range: &R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    (start, end)
+}
+
+/// The shape of a strided selection from a view with the given dimensions.
+struct Strided {
+    row_offset: usize,
+    column_offset: usize,
+    num_rows: usize,
+    num_columns: usize,
+}
+
+impl Strided {
+    fn new(
+        num_rows: usize,
+        num_columns: usize,
+        (row_offset, column_offset): (usize, usize),
+        row_step: usize,
+        column_step: usize,
+    ) -> Self {
+        assert!(row_step != 0 && column_step != 0, "step must be non-zero");
+        Strided {
+            row_offset,
+            column_offset,
+            num_rows: num_rows.saturating_sub(row_offset).div_ceil(row_step),
+            num_columns: num_columns
+                .saturating_sub(column_offset)
+                .div_ceil(column_step),
+        }
+    }
+
+    /// The offset of the first selected element from the start of the
+    /// original view, or zero if nothing is selected.
+    fn start(&self, row_stride: usize, column_stride: usize) -> usize {
+        if self.num_rows == 0 || self.num_columns == 0 {
+            0
+        } else {
+            self.row_offset * row_stride + self.column_offset * column_stride
+        }
+    }
+}
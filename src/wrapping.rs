@@ -0,0 +1,161 @@
+//! Accessing an [`Array2D`] with indices that wrap around its edges, as on a
+//! torus.
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{Array2D, Storage, StorageMut};
+use std::ops::{Index, IndexMut};
+
+/// An adapter around a reference to an [`Array2D`] that is indexed with signed
+/// `(row, column)` indices that wrap around the edges of the array, so that
+/// `-1` is the last row or column and `num_rows` is the first row. Created
+/// by [`Array2D::wrapping`] and [`Array2D::wrapping_mut`].
+///
+/// # Examples
+///
+/// ```
+/// # use array2d::{Array2D, Error, Wrapping};
+/// # fn main() -> Result<(), Error> {
+/// let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+/// let wrapping = array.wrapping();
+/// assert_eq!(wrapping[(-1, -1)], 6);
+/// assert_eq!(wrapping[(2, 4)], 2);
+///
+/// let mut wrapping = Wrapping(&mut array);
+/// wrapping[(0, -1)] = 30;
+/// assert_eq!(array.as_rows(), vec![vec![1, 2, 30], vec![4, 5, 6]]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Indexing panics if the array is empty.
+///
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::wrapping`]: struct.Array2D.html#method.wrapping
+/// [`Array2D::wrapping_mut`]: struct.Array2D.html#method.wrapping_mut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Wrapping<A>(pub A);
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Returns a reference to the element at the given signed `row` and
+    /// `column`, wrapping them around the edges of the array. Returns
+    /// [`None`] only if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    /// assert_eq!(array.get_wrapping(0, -1), Some(&3));
+    /// assert_eq!(array.get_wrapping(-3, 7), Some(&5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_wrapping(&self, row: isize, column: isize) -> Option<&T> {
+        let index = self.wrap_index(row, column)?;
+        self.get(index)
+    }
+
+    /// Returns a mutable reference to the element at the given signed `row`
+    /// and `column`, wrapping them around the edges of the array. Returns
+    /// [`None`] only if the array is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// let mut array = Array2D::filled_with(2, 3, 0);
+    /// *array.get_mut_wrapping(-1, 3).unwrap() = 100;
+    /// assert_eq!(array.get((1, 0)), Some(&100));
+    /// ```
+    ///
+    /// [`None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
+    pub fn get_mut_wrapping(&mut self, row: isize, column: isize) -> Option<&mut T>
+    where
+        S: StorageMut<T>,
+    {
+        let index = self.wrap_index(row, column)?;
+        self.get_mut(index)
+    }
+
+    /// Returns a [`Wrapping`] adapter around a reference to the array, which
+    /// can be indexed with signed indices that wrap around the edges.
+    ///
+    /// [`Wrapping`]: struct.Wrapping.html
+    pub fn wrapping(&self) -> Wrapping<&Self> {
+        Wrapping(self)
+    }
+
+    /// Returns a [`Wrapping`] adapter around a mutable reference to the
+    /// array, which can be indexed with signed indices that wrap around the
+    /// edges.
+    ///
+    /// [`Wrapping`]: struct.Wrapping.html
+    pub fn wrapping_mut(&mut self) -> Wrapping<&mut Self>
+    where
+        S: StorageMut<T>,
+    {
+        Wrapping(self)
+    }
+
+    fn wrap_index(&self, row: isize, column: isize) -> Option<(usize, usize)> {
+        if self.num_rows == 0 || self.num_columns == 0 {
+            return None;
+        }
+        Some((
+            wrap(0, row, self.num_rows),
+            wrap(0, column, self.num_columns),
+        ))
+    }
+}
+
+impl<T, S: Storage<T>> Index<(isize, isize)> for Wrapping<&Array2D<T, S>> {
+    type Output = T;
+
+    fn index(&self, (row, column): (isize, isize)) -> &Self::Output {
+        self.0
+            .get_wrapping(row, column)
+            .unwrap_or_else(|| panic!("Wrapping index {}, {} into an empty array", row, column))
+    }
+}
+
+impl<T, S: Storage<T>> Index<(isize, isize)> for Wrapping<&mut Array2D<T, S>> {
+    type Output = T;
+
+    fn index(&self, (row, column): (isize, isize)) -> &Self::Output {
+        self.0
+            .get_wrapping(row, column)
+            .unwrap_or_else(|| panic!("Wrapping index {}, {} into an empty array", row, column))
+    }
+}
+
+impl<T, S: StorageMut<T>> IndexMut<(isize, isize)> for Wrapping<&mut Array2D<T, S>> {
+    fn index_mut(&mut self, (row, column): (isize, isize)) -> &mut Self::Output {
+        self.0
+            .get_mut_wrapping(row, column)
+            .unwrap_or_else(|| panic!("Wrapping index mut {}, {} into an empty array", row, column))
+    }
+}
+
+/// Moves `index` by `offset`, wrapping around within `0..len`. The index must
+/// be less than `len`.
+pub(crate) fn wrap(index: usize, offset: isize, len: usize) -> usize {
+    let distance = offset.unsigned_abs() % len;
+    if offset >= 0 {
+        let remaining = len - index;
+        if distance < remaining {
+            index + distance
+        } else {
+            distance - remaining
+        }
+    } else if distance <= index {
+        index - distance
+    } else {
+        index + (len - distance)
+    }
+}
@@ -0,0 +1,185 @@
+//! Combining two [`Array2D`]s of the same dimensions element by element.
+//!
+//! [`Array2D`]: ../struct.Array2D.html
+
+use crate::{Array2D, ElementsRowMajorIter, EnumerateRowMajor, Error, Layout, Storage, StorageMut};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+impl<T, S: Storage<T>> Array2D<T, S> {
+    /// Creates a new [`Array2D`] by calling `zipper` on each pair of elements
+    /// at the same index in this array and `other`. Each pair is traversed in
+    /// [row major order]. Returns an error if the arrays do not have the same
+    /// dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let terrain = Array2D::from_rows(&[vec![1, 2], vec![3, 4]])?;
+    /// let occupied = Array2D::from_rows(&[vec![false, true], vec![true, false]])?;
+    /// let cost = terrain.zip_with(&occupied, |&cost, &occupied| if occupied { 0 } else { cost })?;
+    /// assert_eq!(cost.as_rows(), vec![vec![1, 0], vec![0, 4]]);
+    ///
+    /// let wrong_size = Array2D::filled_with(2, 3, false);
+    /// assert_eq!(
+    ///     terrain.zip_with(&wrong_size, |_, _| 0),
+    ///     Err(Error::ShapeMismatch {
+    ///         expected: (2, 2),
+    ///         actual: (2, 3)
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Array2D`]: struct.Array2D.html
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn zip_with<U, R, V, F>(
+        &self,
+        other: &Array2D<U, R>,
+        mut zipper: F,
+    ) -> Result<Array2D<V>, Error>
+    where
+        R: Storage<U>,
+        F: FnMut(&T, &U) -> V,
+    {
+        let array = self
+            .zip(other)?
+            .map(|(_, (left, right))| zipper(left, right))
+            .collect();
+        Ok(Array2D {
+            array,
+            num_rows: self.num_rows,
+            num_columns: self.num_columns,
+            layout: Layout::RowMajor,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns an [`Iterator`] over the pairs of elements at the same index in
+    /// this array and `other`, in [row major order]. Each [`Item`] is a tuple
+    /// of the index and the pair of references. Returns an error if the
+    /// arrays do not have the same dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let numbers = Array2D::from_rows(&[vec![1, 2], vec![3, 4]])?;
+    /// let letters = Array2D::from_rows(&[vec!['a', 'b'], vec!['c', 'd']])?;
+    /// let mut zip = numbers.zip(&letters)?;
+    /// assert_eq!(zip.next(), Some(((0, 0), (&1, &'a'))));
+    /// assert_eq!(zip.next_back(), Some(((1, 1), (&4, &'d'))));
+    /// assert_eq!(zip.len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+    /// [`Item`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html#associatedtype.Item
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn zip<'a, 'b, U, R>(&'a self, other: &'b Array2D<U, R>) -> Result<Zip<'a, 'b, T, U>, Error>
+    where
+        R: Storage<U>,
+    {
+        self.check_same_dimensions(other)?;
+        Ok(Zip {
+            left: self.enumerate_row_major(),
+            right: other.elements_row_major_iter(),
+        })
+    }
+
+    /// Changes every element in place by calling `zipper` on a mutable
+    /// reference to it and a reference to the element at the same index in
+    /// `other`. Each pair is traversed in [row major order]. Returns an error,
+    /// without changing anything, if the arrays do not have the same
+    /// dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use array2d::{Array2D, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let mut totals = Array2D::from_rows(&[vec![1, 2], vec![3, 4]])?;
+    /// let increments = Array2D::from_rows(&[vec![10, 20], vec![30, 40]])?;
+    /// totals.zip_apply(&increments, |total, increment| *total += increment)?;
+    /// assert_eq!(totals.as_rows(), vec![vec![11, 22], vec![33, 44]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [row major order]: https://en.wikipedia.org/wiki/Row-_and_column-major_order
+    pub fn zip_apply<U, R, F>(&mut self, other: &Array2D<U, R>, mut zipper: F) -> Result<(), Error>
+    where
+        S: StorageMut<T>,
+        R: Storage<U>,
+        F: FnMut(&mut T, &U),
+    {
+        self.check_same_dimensions(other)?;
+        self.elements_row_major_iter_mut()
+            .zip(other.elements_row_major_iter())
+            .for_each(|(left, right)| zipper(left, right));
+        Ok(())
+    }
+
+    fn check_same_dimensions<U, R>(&self, other: &Array2D<U, R>) -> Result<(), Error> {
+        if (self.num_rows, self.num_columns) == (other.num_rows, other.num_columns) {
+            Ok(())
+        } else {
+            Err(Error::ShapeMismatch {
+                expected: (self.num_rows, self.num_columns),
+                actual: (other.num_rows, other.num_columns),
+            })
+        }
+    }
+}
+
+/// An [`Iterator`] over the pairs of elements at the same index in two
+/// [`Array2D`]s along with their indices, created by [`Array2D::zip`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/std/iter/trait.Iterator.html
+/// [`Array2D`]: struct.Array2D.html
+/// [`Array2D::zip`]: struct.Array2D.html#method.zip
+#[derive(Debug)]
+pub struct Zip<'a, 'b, T, U> {
+    left: EnumerateRowMajor<'a, T>,
+    right: ElementsRowMajorIter<'b, U>,
+}
+
+impl<T, U> Clone for Zip<'_, '_, T, U> {
+    fn clone(&self) -> Self {
+        Zip {
+            left: self.left.clone(),
+            right: self.right.clone(),
+        }
+    }
+}
+
+impl<'a, 'b, T, U> Iterator for Zip<'a, 'b, T, U> {
+    type Item = ((usize, usize), (&'a T, &'b U));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, left) = self.left.next()?;
+        let right = self.right.next()?;
+        Some((index, (left, right)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.left.size_hint()
+    }
+}
+
+impl<T, U> DoubleEndedIterator for Zip<'_, '_, T, U> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (index, left) = self.left.next_back()?;
+        let right = self.right.next_back()?;
+        Some((index, (left, right)))
+    }
+}
+
+impl<T, U> ExactSizeIterator for Zip<'_, '_, T, U> {}
+
+impl<T, U> FusedIterator for Zip<'_, '_, T, U> {}
@@ -1,4 +1,14 @@
-use array2d::{Array2D, Error};
+#![allow(clippy::needless_range_loop, clippy::useless_vec)]
+
+use array2d::{
+    column_major_index, coords_from_column_major, coords_from_row_major, row_major_index, Array2D,
+    ColumnIter, ColumnsIter, Coord, DiagonalIter, DiagonalIterMut, Direction, Error, Layout,
+    Orientation, RowIter, Traversal, Wrapping,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::sync::Arc;
 
 ////////////////////////////////////////////////////////////////////////////////
 // Normal Operation ////////////////////////////////////////////////////////////
@@ -29,7 +39,7 @@ fn test_from_row_major() -> Result<(), Error> {
     let array = Array2D::from_row_major(num_rows, num_columns, &row_major)?;
     for (row_index, row) in rows.iter().enumerate() {
         for (column_index, element) in row.iter().enumerate() {
-            assert_eq!(array.get(row_index, column_index), Some(element));
+            assert_eq!(array.get((row_index, column_index)), Some(element));
         }
     }
     Ok(())
@@ -44,7 +54,7 @@ fn test_from_column_major() -> Result<(), Error> {
     let array = Array2D::from_column_major(num_rows, num_columns, &column_major)?;
     for (row_index, row) in rows.iter().enumerate() {
         for (column_index, element) in row.iter().enumerate() {
-            assert_eq!(array.get(row_index, column_index), Some(element));
+            assert_eq!(array.get((row_index, column_index)), Some(element));
         }
     }
     Ok(())
@@ -208,6 +218,41 @@ fn test_map_with_index_column_major() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn test_map_in_place() -> Result<(), Error> {
+    let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    let mut visited = Vec::new();
+    array.map_in_place(|x| {
+        visited.push(*x);
+        *x *= 2;
+    });
+    assert_eq!(visited, vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(array.as_rows(), vec![vec![2, 4, 6], vec![8, 10, 12]]);
+    Ok(())
+}
+
+#[test]
+fn test_map_in_place_with_index() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(2, 3, (0, 0));
+    array.map_in_place_with_index(|index, element| *element = index);
+    assert_eq!(
+        array,
+        Array2D::from_iter_row_major(2, 3, array.indices_row_major())?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_into_map() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![1u32, 2, 3], vec![4, 5, 6]])?;
+    let mapped = array.into_map(|x| x as i32 - 3);
+    assert_eq!(mapped.as_rows(), vec![vec![-2, -1, 0], vec![1, 2, 3]]);
+    let widened = mapped.into_map(|x| (x as u8, x as i64));
+    assert_eq!(widened.num_rows(), 2);
+    assert_eq!(widened[(1, 2)], (3, 3));
+    Ok(())
+}
+
 #[test]
 fn test_dimensions() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
@@ -225,7 +270,7 @@ fn test_get() -> Result<(), Error> {
     let array = Array2D::from_rows(&rows)?;
     for row in 0..rows.len() {
         for column in 0..rows[0].len() {
-            assert_eq!(array.get(row, column), Some(&rows[row][column]));
+            assert_eq!(array.get((row, column)), Some(&rows[row][column]));
         }
     }
     Ok(())
@@ -265,7 +310,7 @@ fn test_get_mut() -> Result<(), Error> {
     let mut array = Array2D::from_rows(&rows)?;
     let (set_row, set_column) = (0, 2);
     let element = 53;
-    let element_ref_option = array.get_mut(set_row, set_column);
+    let element_ref_option = array.get_mut((set_row, set_column));
     assert!(element_ref_option.is_some());
     let element_ref = element_ref_option.unwrap();
     assert_eq!(element_ref, &rows[set_row][set_column]);
@@ -273,7 +318,7 @@ fn test_get_mut() -> Result<(), Error> {
     assert_eq!(element_ref, &element);
     for row in 0..rows.len() {
         for column in 0..rows[0].len() {
-            let actual = array.get(row, column);
+            let actual = array.get((row, column));
             if (row, column) == (set_row, set_column) {
                 assert_eq!(actual, Some(&element));
             } else {
@@ -312,16 +357,184 @@ fn test_get_mut_column_major() -> Result<(), Error> {
     Ok(())
 }
 
+#[test]
+fn test_index_conversions() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 4, 0..)?;
+    for (index, element) in array.elements_row_major_iter().enumerate() {
+        let coords = array.coords_from_row_major(index).unwrap();
+        assert_eq!(array[coords], *element);
+        assert_eq!(array.row_major_index(coords), Some(index));
+        assert_eq!(row_major_index(coords.0, coords.1, 4), index);
+        assert_eq!(coords_from_row_major(index, 4), coords);
+    }
+    for (index, element) in array.elements_column_major_iter().enumerate() {
+        let coords = array.coords_from_column_major(index).unwrap();
+        assert_eq!(array[coords], *element);
+        assert_eq!(array.column_major_index(coords), Some(index));
+        assert_eq!(column_major_index(coords.0, coords.1, 3), index);
+        assert_eq!(coords_from_column_major(index, 3), coords);
+    }
+    assert_eq!(array.coords_from_row_major(12), None);
+    assert_eq!(array.coords_from_column_major(12), None);
+    assert_eq!(array.row_major_index((0, 4)), None);
+    assert_eq!(array.column_major_index((-1_i32, 0)), None);
+    Ok(())
+}
+
+#[test]
+fn test_index_conversions_empty() {
+    let mut array = Array2D::<i32>::filled_with(0, 3, 0);
+    assert_eq!(array.coords_from_column_major(0), None);
+    assert_eq!(array.get_column_major(0), None);
+    assert_eq!(array.get_mut_column_major(0), None);
+    let array = Array2D::<i32>::filled_with(3, 0, 0);
+    assert_eq!(array.coords_from_row_major(0), None);
+}
+
+#[test]
+fn test_get_signed() -> Result<(), Error> {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let mut array = Array2D::from_rows(&rows)?;
+    assert_eq!(array.get_signed(0, 0), Some(&1));
+    assert_eq!(array.get_signed(1, 2), Some(&6));
+    assert_eq!(array.get_signed(-1, 0), None);
+    assert_eq!(array.get_signed(0, -1), None);
+    assert_eq!(array.get_signed(2, 0), None);
+    assert_eq!(array.get_signed(isize::MIN, isize::MAX), None);
+    *array.get_mut_signed(1, 0).unwrap() = 40;
+    assert_eq!(array.get_mut_signed(-1, -1), None);
+    assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![40, 5, 6]]);
+    Ok(())
+}
+
+#[test]
+fn test_step() -> Result<(), Error> {
+    let array = Array2D::filled_with(2, 3, 0);
+    assert_eq!(array.step((0, 0), (0, 0)), Some((0, 0)));
+    assert_eq!(array.step((0, 0), (1, 2)), Some((1, 2)));
+    assert_eq!(array.step((1, 2), (-1, -2)), Some((0, 0)));
+    assert_eq!(array.step((0, 1), (-1, 0)), None);
+    assert_eq!(array.step((1, 0), (0, -1)), None);
+    assert_eq!(array.step((1, 2), (1, 0)), None);
+    assert_eq!(array.step((1, 2), (0, 1)), None);
+    assert_eq!(array.step((usize::MAX, 0), (isize::MIN, 0)), None);
+    assert_eq!(array.step((usize::MAX, 0), (1, 0)), None);
+    Ok(())
+}
+
+#[test]
+fn test_get_offset() -> Result<(), Error> {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let mut array = Array2D::from_rows(&rows)?;
+    assert_eq!(array.get_offset((1, 1), (-1, -1)), Some(&1));
+    assert_eq!(array.get_offset((1, 1), (0, 1)), Some(&6));
+    assert_eq!(array.get_offset((0, 1), (-1, 0)), None);
+    assert_eq!(array.get_offset((1, 1), (0, 2)), None);
+    *array.get_mut_offset((0, 2), (1, -2)).unwrap() = 40;
+    assert_eq!(array.get_mut_offset((0, 0), (0, -1)), None);
+    assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![40, 5, 6]]);
+    Ok(())
+}
+
+#[test]
+fn test_get_index_types() -> Result<(), Error> {
+    let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(array.get([1, 2]), Some(&6));
+    assert_eq!(array.get((1_u8, 2_u8)), Some(&6));
+    assert_eq!(array.get([1_u32, 0]), Some(&4));
+    assert_eq!(array.get((0_i32, 2_i32)), Some(&3));
+    assert_eq!(array.get([0_i64, 1]), Some(&2));
+    assert_eq!(array.get(Coord::new(1, 1)), Some(&5));
+    assert_eq!(array.get((2_u16, 0)), None);
+    assert_eq!(array.get((-1_i32, 0)), None);
+    assert_eq!(array.get([0_isize, -1]), None);
+    assert_eq!(array.get((u128::MAX, 0)), None);
+    assert_eq!(array.get_mut((i8::MIN, 0)), None);
+    *array.get_mut([1_i16, 2]).unwrap() = 60;
+    assert_eq!(array[(1_u64, 2_u64)], 60);
+    assert_eq!(array[[0, 0]], 1);
+    Ok(())
+}
+
+#[test]
+fn test_set_negative_index() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    array.set((1_i32, 2_i32), 1)?;
+    assert_eq!(
+        array.set((-1_i32, 2), 2),
+        Err(Error::IndicesOutOfBounds {
+            row: usize::MAX,
+            column: 2,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    assert_eq!(
+        array.set([0, -3_i64], 3),
+        Err(Error::IndicesOutOfBounds {
+            row: 0,
+            column: usize::MAX,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    assert_eq!(array.as_rows(), vec![vec![0, 0, 0], vec![0, 0, 1]]);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_op_index_negative() {
+    let array = Array2D::filled_with(2, 3, 0);
+    let _ = array[(0_i32, -1_i32)];
+}
+
+#[test]
+fn test_get_unchecked() -> Result<(), Error> {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let mut array = Array2D::from_rows(&rows)?;
+    for (row, row_elements) in rows.iter().enumerate() {
+        for (column, element) in row_elements.iter().enumerate() {
+            assert_eq!(unsafe { array.get_unchecked((row, column)) }, element);
+        }
+    }
+    for (index, element) in rows.concat().iter().enumerate() {
+        assert_eq!(unsafe { array.get_unchecked_row_major(index) }, element);
+    }
+    unsafe {
+        *array.get_unchecked_mut([0_i32, 2]) = 30;
+        *array.get_unchecked_mut_row_major(4) = 50;
+    }
+    assert_eq!(array.as_rows(), vec![vec![1, 2, 30], vec![4, 50, 6]]);
+    Ok(())
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Unchecked indices 0, 3 out of bounds")]
+fn test_get_unchecked_out_of_bounds() {
+    let array = Array2D::filled_with(2, 3, 0);
+    let _ = unsafe { array.get_unchecked((0, 3)) };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Unchecked index 6 out of bounds")]
+fn test_get_unchecked_mut_row_major_out_of_bounds() {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    let _ = unsafe { array.get_unchecked_mut_row_major(6) };
+}
+
 #[test]
 fn test_set() -> Result<(), Error> {
     let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
     let mut array = Array2D::from_rows(&rows)?;
     let (set_row, set_column) = (1, 0);
     let element = 42;
-    array.set(set_row, set_column, element).unwrap();
+    array.set((set_row, set_column), element).unwrap();
     for row in 0..rows.len() {
         for column in 0..rows[0].len() {
-            let actual = array.get(row, column);
+            let actual = array.get((row, column));
             if (row, column) == (set_row, set_column) {
                 assert_eq!(actual, Some(&element));
             } else {
@@ -343,7 +556,7 @@ fn test_set_row_major() -> Result<(), Error> {
     array.set_row_major(set_index, element).unwrap();
     for row in 0..rows.len() {
         for column in 0..rows[0].len() {
-            let actual = array.get(row, column);
+            let actual = array.get((row, column));
             if (row, column) == (set_row, set_column) {
                 assert_eq!(actual, Some(&element));
             } else {
@@ -365,7 +578,7 @@ fn test_set_column_major() -> Result<(), Error> {
     array.set_column_major(set_index, element).unwrap();
     for row in 0..rows.len() {
         for column in 0..rows[0].len() {
-            let actual = array.get(row, column);
+            let actual = array.get((row, column));
             if (row, column) == (set_row, set_column) {
                 assert_eq!(actual, Some(&element));
             } else {
@@ -496,11 +709,45 @@ fn test_swap() -> Result<(), Error> {
     let expected_rows = vec![vec![1, 4, 3], vec![2, 5, 6]];
     assert_eq!(array.as_rows(), expected_rows);
 
-    let expected_err = Error::IndicesOutOfBounds(2, 0);
+    let expected_err = Error::IndicesOutOfBounds {
+        row: 2,
+        column: 0,
+        num_rows: 2,
+        num_columns: 3,
+    };
     assert_eq!(array.swap((0, 1), (2, 0)).unwrap_err(), expected_err);
     // The failed swap should not have modified the array.
     assert_eq!(array.as_rows(), expected_rows);
 
+    assert!(array.swap([0_u32, 0], Coord::new(1, 2)).is_ok());
+    assert_eq!(array.as_rows(), vec![vec![6, 4, 3], vec![2, 5, 1]]);
+    let expected_err = Error::IndicesOutOfBounds {
+        row: 0,
+        column: usize::MAX,
+        num_rows: 2,
+        num_columns: 3,
+    };
+    assert_eq!(
+        array.swap((0, 0), (0_i32, -1_i32)).unwrap_err(),
+        expected_err
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_get_many_mut() -> Result<(), Error> {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let mut array = Array2D::from_rows(&rows)?;
+
+    let [a, b, c] = array.get_many_mut([(1, 2), (0, 0), (0, 1)])?;
+    assert_eq!((*a, *b, *c), (6, 1, 2));
+    *a += 10;
+    *b += 20;
+    *c += 30;
+    assert_eq!(array.as_rows(), vec![vec![21, 32, 3], vec![4, 5, 16]]);
+
+    let [] = array.get_many_mut([])?;
     Ok(())
 }
 
@@ -512,14 +759,26 @@ fn test_swap() -> Result<(), Error> {
 fn test_from_rows_not_all_same_size() {
     let rows = vec![vec![1, 2, 3], vec![4, 5]];
     let result = Array2D::from_rows(&rows);
-    assert_eq!(result, Err(Error::DimensionMismatch));
+    assert_eq!(
+        result,
+        Err(Error::DimensionMismatch {
+            expected: 3,
+            actual: 2
+        })
+    );
 }
 
 #[test]
 fn test_from_columns_not_all_same_size() {
     let columns = vec![vec![1, 4], vec![2, 3], vec![4]];
     let result = Array2D::from_columns(&columns);
-    assert_eq!(result, Err(Error::DimensionMismatch));
+    assert_eq!(
+        result,
+        Err(Error::DimensionMismatch {
+            expected: 2,
+            actual: 1
+        })
+    );
 }
 
 #[test]
@@ -528,7 +787,13 @@ fn test_from_row_major_dimensions_do_not_match_size() {
     let num_rows = 2;
     let num_columns = 3;
     let result = Array2D::from_row_major(num_rows, num_columns, &row_major);
-    assert_eq!(result, Err(Error::DimensionMismatch));
+    assert_eq!(
+        result,
+        Err(Error::DimensionMismatch {
+            expected: 6,
+            actual: 7
+        })
+    );
 }
 
 #[test]
@@ -537,7 +802,13 @@ fn test_from_column_major_dimensions_do_not_match_size() {
     let num_rows = 2;
     let num_columns = 3;
     let result = Array2D::from_column_major(num_rows, num_columns, &column_major);
-    assert_eq!(result, Err(Error::DimensionMismatch));
+    assert_eq!(
+        result,
+        Err(Error::DimensionMismatch {
+            expected: 6,
+            actual: 5
+        })
+    );
 }
 
 #[test]
@@ -546,7 +817,13 @@ fn test_from_iter_row_major_not_enough() {
     let num_rows = 2;
     let num_columns = 3;
     let result = Array2D::from_iter_row_major(num_rows, num_columns, iter);
-    assert_eq!(result, Err(Error::NotEnoughElements));
+    assert_eq!(
+        result,
+        Err(Error::NotEnoughElements {
+            expected: 6,
+            actual: 4
+        })
+    );
 }
 
 #[test]
@@ -555,7 +832,13 @@ fn test_from_iter_column_major_not_enough() {
     let num_rows = 2;
     let num_columns = 3;
     let result = Array2D::from_iter_column_major(num_rows, num_columns, iter);
-    assert_eq!(result, Err(Error::NotEnoughElements));
+    assert_eq!(
+        result,
+        Err(Error::NotEnoughElements {
+            expected: 6,
+            actual: 4
+        })
+    );
 }
 
 #[test]
@@ -565,7 +848,21 @@ fn test_row_iter_out_of_bounds() {
     let num_columns = 3;
     let array = Array2D::filled_with(num_rows, num_columns, element);
     let result = array.row_iter(num_rows);
-    assert!(result.is_err());
+    assert_eq!(
+        result.err(),
+        Some(Error::RowOutOfBounds {
+            row: num_rows,
+            num_rows
+        })
+    );
+}
+
+#[test]
+fn test_row_iter_no_columns() -> Result<(), Error> {
+    let array = Array2D::<i32>::filled_with(2, 0, 0);
+    assert_eq!(array.row_iter(1)?.count(), 0);
+    assert!(array.row_iter(2).is_err());
+    Ok(())
 }
 
 #[test]
@@ -575,7 +872,106 @@ fn test_column_iter_out_of_bounds() {
     let num_columns = 3;
     let array = Array2D::filled_with(num_rows, num_columns, element);
     let result = array.column_iter(num_columns);
-    assert!(result.is_err());
+    assert_eq!(
+        result.err(),
+        Some(Error::ColumnOutOfBounds {
+            column: num_columns,
+            num_columns
+        })
+    );
+}
+
+#[test]
+fn test_error_display() {
+    let error = Error::IndicesOutOfBounds {
+        row: 2,
+        column: 0,
+        num_rows: 2,
+        num_columns: 3,
+    };
+    assert_eq!(
+        error.to_string(),
+        "indices (2, 0) out of bounds for array with 2 rows and 3 columns"
+    );
+    let error = Error::IndexOutOfBounds {
+        index: 6,
+        num_elements: 6,
+    };
+    assert_eq!(
+        error.to_string(),
+        "index 6 out of bounds for array with 6 elements"
+    );
+    let error = Error::RowOutOfBounds {
+        row: 4,
+        num_rows: 2,
+    };
+    assert_eq!(
+        error.to_string(),
+        "row 4 out of bounds for array with 2 rows"
+    );
+    let error = Error::DimensionMismatch {
+        expected: 6,
+        actual: 7,
+    };
+    assert_eq!(
+        error.to_string(),
+        "dimension mismatch: expected length 6 but got 7"
+    );
+    let error = Error::ShapeMismatch {
+        expected: (2, 3),
+        actual: (3, 2),
+    };
+    assert_eq!(
+        error.to_string(),
+        "shape mismatch: expected 2 rows and 3 columns but got 3 rows and 2 columns"
+    );
+    let error = Error::NotEnoughElements {
+        expected: 6,
+        actual: 4,
+    };
+    assert_eq!(
+        error.to_string(),
+        "not enough elements: expected 6 but got 4"
+    );
+}
+
+#[test]
+fn test_get_many_mut_out_of_bounds() {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    let result = array.get_many_mut([(0, 0), (2, 1)]);
+    assert_eq!(
+        result.err(),
+        Some(Error::IndicesOutOfBounds {
+            row: 2,
+            column: 1,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    let result = array.get_many_mut([(0, 3)]);
+    assert_eq!(
+        result.err(),
+        Some(Error::IndicesOutOfBounds {
+            row: 0,
+            column: 3,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+}
+
+#[test]
+fn test_get_many_mut_duplicate_indices() {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    let result = array.get_many_mut([(0, 0), (1, 2), (0, 0)]);
+    assert_eq!(
+        result.err(),
+        Some(Error::DuplicateIndices { row: 0, column: 0 })
+    );
+    assert_eq!(
+        Error::DuplicateIndices { row: 0, column: 0 }.to_string(),
+        "indices (0, 0) given more than once"
+    );
 }
 
 #[test]
@@ -638,6 +1034,42 @@ fn test_index_mut_out_of_bounds_row_and_column() {
     array[(num_rows, num_columns)] += 1;
 }
 
+#[test]
+fn test_from_row_major_dimension_overflow() {
+    let num_rows = usize::MAX / 2 + 1;
+    assert_eq!(
+        Array2D::from_row_major(num_rows, 2, &[0]).err(),
+        Some(Error::DimensionOverflow {
+            num_rows,
+            num_columns: 2
+        })
+    );
+    assert_eq!(
+        Array2D::from_iter_column_major(num_rows, 2, 0..).err(),
+        Some(Error::DimensionOverflow {
+            num_rows,
+            num_columns: 2
+        })
+    );
+}
+
+#[test]
+fn test_from_slice_dimension_overflow() {
+    let num_columns = usize::MAX / 2 + 1;
+    let error = Array2D::from_slice(2, num_columns, &[0]).unwrap_err();
+    assert_eq!(
+        error,
+        Error::DimensionOverflow {
+            num_rows: 2,
+            num_columns
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        format!("array with 2 rows and {num_columns} columns has too many elements")
+    );
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Empty Arrays ////////////////////////////////////////////////////////////////
 ////////////////////////////////////////////////////////////////////////////////
@@ -820,3 +1252,2383 @@ fn main() -> Result<(), Error> {
     );
     Ok(())
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Chunks //////////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_chunks() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 4, 1..)?;
+    let chunks = array
+        .chunks(2, 3)
+        .map(|(block, chunk)| (block, chunk.to_owned().as_rows()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        chunks,
+        vec![
+            ((0, 0), vec![vec![1, 2, 3], vec![5, 6, 7]]),
+            ((0, 1), vec![vec![4], vec![8]]),
+            ((1, 0), vec![vec![9, 10, 11]]),
+            ((1, 1), vec![vec![12]]),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chunks_covers_every_element_once() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(7, 5, 0..)?;
+    let mut seen = array
+        .chunks(3, 2)
+        .flat_map(|(_, chunk)| chunk.elements_row_major_iter().cloned().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    seen.sort_unstable();
+    assert_eq!(seen, (0..35).collect::<Vec<_>>());
+    assert_eq!(array.chunks(3, 2).len(), 9);
+    Ok(())
+}
+
+#[test]
+fn test_chunks_exact() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(5, 5, 1..)?;
+    let chunks = array.chunks_exact(2, 2);
+    assert_eq!(chunks.len(), 4);
+    let (right, bottom) = chunks.remainder();
+    assert_eq!(
+        right.to_owned().as_rows(),
+        vec![vec![5], vec![10], vec![15], vec![20]]
+    );
+    assert_eq!(bottom.to_owned().as_rows(), vec![vec![21, 22, 23, 24, 25]]);
+    let chunks = chunks
+        .map(|(block, chunk)| (block, chunk.to_owned().as_rows()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        chunks,
+        vec![
+            ((0, 0), vec![vec![1, 2], vec![6, 7]]),
+            ((0, 1), vec![vec![3, 4], vec![8, 9]]),
+            ((1, 0), vec![vec![11, 12], vec![16, 17]]),
+            ((1, 1), vec![vec![13, 14], vec![18, 19]]),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chunks_exact_no_remainder() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(4, 6, 1..)?;
+    let chunks = array.chunks_exact(2, 3);
+    let (right, bottom) = chunks.remainder();
+    assert_eq!(right.num_elements(), 0);
+    assert_eq!(bottom.num_elements(), 0);
+    assert_eq!(chunks.count(), 4);
+    Ok(())
+}
+
+#[test]
+fn test_chunks_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(3, 5, 0);
+    let mut chunks = array.chunks_mut(2, 2).collect::<Vec<_>>();
+    // All chunks can be held and written to at the same time.
+    for (index, (_, chunk)) in chunks.iter_mut().enumerate() {
+        for element in chunk.elements_row_major_iter_mut() {
+            *element = index;
+        }
+    }
+    assert_eq!(
+        array.as_rows(),
+        vec![
+            vec![0, 0, 1, 1, 2],
+            vec![0, 0, 1, 1, 2],
+            vec![3, 3, 4, 4, 5],
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_chunks_exact_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(3, 3, 0);
+    let mut chunks = array.chunks_exact_mut(2, 2);
+    let (block, mut chunk) = chunks.next().unwrap();
+    assert_eq!(block, (0, 0));
+    assert!(chunks.next().is_none());
+    chunk.set((1, 1), 1)?;
+    let (mut right, mut bottom) = chunks.into_remainder();
+    assert_eq!((right.num_rows(), right.num_columns()), (2, 1));
+    assert_eq!((bottom.num_rows(), bottom.num_columns()), (1, 3));
+    right[(1, 0)] = 2;
+    bottom[(0, 0)] = 3;
+    assert_eq!(
+        array.as_rows(),
+        vec![vec![0, 0, 0], vec![0, 1, 2], vec![3, 0, 0]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_double_ended_iterator_chunks() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 3, 1..)?;
+    let blocks = array
+        .chunks(2, 2)
+        .rev()
+        .map(|(block, _)| block)
+        .collect::<Vec<_>>();
+    assert_eq!(blocks, vec![(1, 1), (1, 0), (0, 1), (0, 0)]);
+    Ok(())
+}
+
+#[test]
+fn test_chunks_empty_array() {
+    let array: Array2D<i32> = Array2D::filled_with(0, 4, 0);
+    assert_eq!(array.chunks(2, 2).count(), 0);
+    assert_eq!(array.chunks_exact(2, 2).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_chunks_zero_size() {
+    let array = Array2D::filled_with(2, 2, 0);
+    let _ = array.chunks(0, 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Strided Views ///////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_step_by() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(5, 7, 0..)?;
+    let view = array.step_by(2, 3);
+    assert_eq!(view.num_rows(), 3);
+    assert_eq!(view.num_columns(), 3);
+    assert_eq!(view.get((1, 2)), Some(&20));
+    assert_eq!(view.get((3, 0)), None);
+    assert_eq!(view.get((1_i32, -2_i32)), None);
+    assert_eq!(view[(2, 1)], 31);
+    assert_eq!(view[[1_u8, 2]], 20);
+    assert_eq!(
+        view.to_owned().as_rows(),
+        vec![vec![0, 3, 6], vec![14, 17, 20], vec![28, 31, 34]]
+    );
+    assert_eq!(
+        view.column_iter(1)?.cloned().collect::<Vec<_>>(),
+        vec![3, 17, 31]
+    );
+    assert_eq!(
+        view.elements_column_major_iter()
+            .rev()
+            .cloned()
+            .collect::<Vec<_>>(),
+        vec![34, 20, 6, 31, 17, 3, 28, 14, 0]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_step_by_offset() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(4, 6, 0..)?;
+    let view = array.step_by_offset((1, 1), 2, 2);
+    assert_eq!(
+        view.to_owned().as_rows(),
+        vec![vec![7, 9, 11], vec![19, 21, 23]]
+    );
+    let view = array.step_by_offset((3, 5), 10, 10);
+    assert_eq!(view.to_owned().as_rows(), vec![vec![23]]);
+    Ok(())
+}
+
+#[test]
+fn test_step_by_offset_out_of_bounds_is_empty() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(2, 3, 0..)?;
+    let view = array.step_by_offset((2, 0), 1, 1);
+    assert_eq!(view.num_rows(), 0);
+    assert_eq!(view.num_columns(), 3);
+    assert_eq!(view.elements_row_major_iter().count(), 0);
+    let view = array.step_by_offset((0, 7), 1, 1);
+    assert_eq!(view.num_columns(), 0);
+    assert!(view.get((0, 0)).is_none());
+    Ok(())
+}
+
+#[test]
+fn test_step_by_composes() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(8, 8, 0..)?;
+    let view = array.step_by(2, 2).step_by_offset((1, 0), 2, 3);
+    assert_eq!(view.to_owned().as_rows(), vec![vec![16, 22], vec![48, 54]]);
+    Ok(())
+}
+
+#[test]
+fn test_step_by_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(3, 4, 0);
+    array
+        .step_by_offset_mut((0, 1), 2, 2)
+        .elements_row_major_iter_mut()
+        .for_each(|element| *element = 1);
+    let mut view = array.step_by_mut(1, 3);
+    view[(2, 1)] = 2;
+    view.step_by_mut(2, 1).set((0, 0), 3)?;
+    assert_eq!(
+        array.as_rows(),
+        vec![vec![3, 1, 0, 1], vec![0, 0, 0, 0], vec![0, 1, 0, 2]]
+    );
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_step_by_zero() {
+    let array = Array2D::filled_with(2, 2, 0);
+    let _ = array.step_by(1, 0);
+}
+
+#[test]
+fn test_step_by_max_step() -> Result<(), Error> {
+    let mut array = Array2D::from_iter_row_major(3, 4, 0..)?;
+    let view = array.step_by(usize::MAX, usize::MAX);
+    assert_eq!(view.to_owned().as_rows(), vec![vec![0]]);
+    let view = array
+        .step_by_offset((1, 2), usize::MAX, 1)
+        .step_by(1, usize::MAX);
+    assert_eq!(view.to_owned().as_rows(), vec![vec![6]]);
+    let mut view = array.step_by_mut(usize::MAX, usize::MAX);
+    view[(0, 0)] = 42;
+    let column_major = array.into_layout(Layout::ColumnMajor);
+    let view = column_major.step_by(1, usize::MAX);
+    assert_eq!(view.to_owned().as_rows(), vec![vec![42], vec![4], vec![8]]);
+    Ok(())
+}
+
+#[test]
+fn test_slice() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 4, 0..)?;
+    let view = array.slice((1..3, 1..=2))?;
+    assert_eq!(view.to_owned().as_rows(), vec![vec![5, 6], vec![9, 10]]);
+    assert_eq!(view.get((1, 1)), Some(&10));
+    assert_eq!(view.get((2, 0)), None);
+    assert_eq!(array.slice((.., ..))?.to_owned(), array);
+    assert_eq!(array.slice((..1, 3..))?.to_owned().as_rows(), vec![vec![3]]);
+    let empty = array.slice((3.., 1..1))?;
+    assert_eq!((empty.num_rows(), empty.num_columns()), (0, 0));
+    assert_eq!(empty.elements_row_major_iter().count(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_slice_of_view() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(4, 6, 0..)?;
+    let view = array.step_by(1, 2).slice((1.., 1..))?;
+    assert_eq!(
+        view.to_owned().as_rows(),
+        vec![vec![8, 10], vec![14, 16], vec![20, 22]]
+    );
+    assert_eq!(
+        view.slice((..=1, 1..))?.to_owned().as_rows(),
+        vec![vec![10], vec![16]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_slice_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(3, 4, 0);
+    array.slice_mut((1.., ..2))?[(1, 1)] = 1;
+    let mut view = array.slice_mut((.., 2..))?;
+    view.slice_mut((..1, ..))?
+        .elements_row_major_iter_mut()
+        .for_each(|element| *element = 2);
+    assert_eq!(view.slice((2.., 1..))?.get((0, 0)), Some(&0));
+    view.set((2, 1), 3)?;
+    assert_eq!(
+        array.as_rows(),
+        vec![vec![0, 0, 2, 2], vec![0, 0, 0, 0], vec![0, 1, 0, 3]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_slice_out_of_bounds() {
+    let mut array = Array2D::filled_with(3, 4, 0);
+    assert_eq!(
+        array.slice((1..4, ..)).err(),
+        Some(Error::RowRangeOutOfBounds {
+            start: 1,
+            end: 4,
+            num_rows: 3
+        })
+    );
+    assert_eq!(
+        array.slice_mut((.., ..=4)).err(),
+        Some(Error::ColumnRangeOutOfBounds {
+            start: 0,
+            end: 5,
+            num_columns: 4
+        })
+    );
+    let (start, end) = (3, 2);
+    let result = array.slice((.., start..end));
+    assert_eq!(
+        result.err(),
+        Some(Error::ColumnRangeOutOfBounds {
+            start: 3,
+            end: 2,
+            num_columns: 4
+        })
+    );
+    assert_eq!(
+        Error::RowRangeOutOfBounds {
+            start: 1,
+            end: 4,
+            num_rows: 3
+        }
+        .to_string(),
+        "row range 1..4 out of bounds for array with 3 rows"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Diagonals ///////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_diagonal() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 4, 1..)?;
+    let diagonal =
+        |offset| -> Result<Vec<i32>, Error> { Ok(array.diagonal(offset)?.cloned().collect()) };
+    assert_eq!(diagonal(0)?, vec![1, 6, 11]);
+    assert_eq!(diagonal(1)?, vec![2, 7, 12]);
+    assert_eq!(diagonal(3)?, vec![4]);
+    assert_eq!(diagonal(-1)?, vec![5, 10]);
+    assert_eq!(diagonal(-2)?, vec![9]);
+    assert_eq!(
+        array.diagonal(0)?.rev().cloned().collect::<Vec<_>>(),
+        vec![11, 6, 1]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_anti_diagonal() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 4, 1..)?;
+    let anti_diagonal =
+        |offset| -> Result<Vec<i32>, Error> { Ok(array.anti_diagonal(offset)?.cloned().collect()) };
+    assert_eq!(anti_diagonal(0)?, vec![4, 7, 10]);
+    assert_eq!(anti_diagonal(1)?, vec![3, 6, 9]);
+    assert_eq!(anti_diagonal(3)?, vec![1]);
+    assert_eq!(anti_diagonal(-1)?, vec![8, 11]);
+    assert_eq!(anti_diagonal(-2)?, vec![12]);
+    Ok(())
+}
+
+#[test]
+fn test_diagonals_iter() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 2, 1..)?;
+    let diagonals = array
+        .diagonals_iter()
+        .map(|diagonal| diagonal.cloned().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(diagonals, vec![vec![5], vec![3, 6], vec![1, 4], vec![2]]);
+    let anti_diagonals = array
+        .anti_diagonals_iter()
+        .rev()
+        .map(|anti_diagonal| anti_diagonal.cloned().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        anti_diagonals,
+        vec![vec![1], vec![2, 3], vec![4, 5], vec![6]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_diagonals_iter_covers_every_element_once() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(4, 7, 0..)?;
+    for mut elements in [
+        array
+            .diagonals_iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>(),
+        array
+            .anti_diagonals_iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>(),
+    ] {
+        elements.sort_unstable();
+        assert_eq!(elements, (0..28).collect::<Vec<_>>());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_diagonal_win_detection() -> Result<(), Error> {
+    let board = Array2D::from_rows(&[
+        vec!['X', 'O', 'O'],
+        vec![' ', 'X', 'O'],
+        vec!['O', ' ', 'X'],
+    ])?;
+    assert!(board.diagonal(0)?.all(|&cell| cell == 'X'));
+    assert!(!board.anti_diagonal(0)?.all(|&cell| cell == 'O'));
+    Ok(())
+}
+
+#[test]
+fn test_diagonal_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(3, 4, 0);
+    for element in array.diagonal_mut(1)? {
+        *element = 1;
+    }
+    for element in array.anti_diagonal_mut(-1)? {
+        *element = 2;
+    }
+    assert_eq!(
+        array.as_rows(),
+        vec![vec![0, 1, 0, 0], vec![0, 0, 1, 2], vec![0, 0, 2, 1]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_diagonal_iter() -> Result<(), Error> {
+    let rows = vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8], vec![9, 10, 11, 12]];
+    for &layout in &[Layout::RowMajor, Layout::ColumnMajor] {
+        let mut array = Array2D::from_rows(&rows)?.into_layout(layout);
+        let mut diagonal: DiagonalIter<_> = array.diagonal(1)?;
+        assert_eq!(diagonal.len(), 3);
+        assert_eq!(diagonal.next_back(), Some(&12));
+        assert_eq!(diagonal.clone().collect::<Vec<_>>(), vec![&2, &7]);
+        assert_eq!(diagonal.nth(1), Some(&7));
+        assert_eq!(diagonal.next(), None);
+        assert_eq!(diagonal.next(), None);
+        let anti_diagonal = array.anti_diagonal(-1)?;
+        assert_eq!(anti_diagonal.len(), 2);
+        assert_eq!(anti_diagonal.rev().collect::<Vec<_>>(), vec![&11, &8]);
+        let anti_diagonal: DiagonalIterMut<_> = array.anti_diagonal_mut(0)?;
+        assert_eq!(anti_diagonal.len(), 3);
+        anti_diagonal.for_each(|element| *element = 0);
+        assert_eq!(
+            array.as_rows(),
+            vec![vec![1, 2, 3, 0], vec![5, 6, 0, 8], vec![9, 0, 11, 12]]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_diagonals_iter_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(3, 3, 0);
+    let mut diagonals = array.diagonals_iter_mut().collect::<Vec<_>>();
+    // All diagonals can be held at the same time.
+    for (index, diagonal) in diagonals.iter_mut().enumerate() {
+        for element in diagonal {
+            *element = index;
+        }
+    }
+    drop(diagonals);
+    assert_eq!(
+        array.as_rows(),
+        vec![vec![2, 3, 4], vec![1, 2, 3], vec![0, 1, 2]]
+    );
+    for (index, anti_diagonal) in array.anti_diagonals_iter_mut().rev().enumerate() {
+        for element in anti_diagonal {
+            *element = index;
+        }
+    }
+    assert_eq!(
+        array.as_rows(),
+        vec![vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_diagonals_single_row_and_column() -> Result<(), Error> {
+    let row = Array2D::from_iter_row_major(1, 3, 1..)?;
+    assert_eq!(row.diagonals_iter().count(), 3);
+    assert_eq!(row.anti_diagonal(0)?.collect::<Vec<_>>(), vec![&3]);
+    assert_eq!(row.anti_diagonal(2)?.collect::<Vec<_>>(), vec![&1]);
+    let column = Array2D::from_iter_row_major(3, 1, 1..)?;
+    assert_eq!(column.anti_diagonal(0)?.collect::<Vec<_>>(), vec![&1]);
+    assert_eq!(column.anti_diagonal(-2)?.collect::<Vec<_>>(), vec![&3]);
+    assert_eq!(
+        column
+            .anti_diagonals_iter()
+            .map(|anti_diagonal| anti_diagonal.count())
+            .collect::<Vec<_>>(),
+        vec![1, 1, 1]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_diagonal_out_of_bounds() {
+    let array = Array2D::filled_with(2, 3, 0);
+    assert_eq!(
+        array.diagonal(3).err(),
+        Some(Error::DiagonalOutOfBounds {
+            offset: 3,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    assert_eq!(
+        array.diagonal(-2).err(),
+        Some(Error::DiagonalOutOfBounds {
+            offset: -2,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    assert_eq!(
+        array.anti_diagonal(3).err(),
+        Some(Error::DiagonalOutOfBounds {
+            offset: 3,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    assert_eq!(
+        array.anti_diagonal(-2).err().map(|error| error.to_string()),
+        Some("diagonal offset -2 out of bounds for array with 2 rows and 3 columns".to_string())
+    );
+    let mut empty: Array2D<i32> = Array2D::filled_with(0, 0, 0);
+    assert!(empty.diagonal(0).is_err());
+    assert!(empty.anti_diagonal_mut(0).is_err());
+    assert_eq!(empty.diagonals_iter().count(), 0);
+    assert_eq!(empty.anti_diagonals_iter_mut().count(), 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Mutable Iterators ///////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_elements_row_major_iter_mut() -> Result<(), Error> {
+    let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    for element in array.elements_row_major_iter_mut() {
+        *element *= 2;
+    }
+    assert_eq!(array.as_rows(), vec![vec![2, 4, 6], vec![8, 10, 12]]);
+    Ok(())
+}
+
+#[test]
+fn test_elements_column_major_iter_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    for (element, value) in array.elements_column_major_iter_mut().zip(1..) {
+        *element = value;
+    }
+    assert_eq!(array.as_columns(), vec![vec![1, 2], vec![3, 4], vec![5, 6]]);
+    Ok(())
+}
+
+#[test]
+fn test_row_iter_mut() -> Result<(), Error> {
+    let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    for element in array.row_iter_mut(0)? {
+        *element = 0;
+    }
+    assert_eq!(array.as_rows(), vec![vec![0, 0, 0], vec![4, 5, 6]]);
+    Ok(())
+}
+
+#[test]
+fn test_column_iter_mut() -> Result<(), Error> {
+    let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    for element in array.column_iter_mut(2)? {
+        *element = 0;
+    }
+    assert_eq!(array.as_rows(), vec![vec![1, 2, 0], vec![4, 5, 0]]);
+    Ok(())
+}
+
+#[test]
+fn test_rows_iter_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(3, 2, 0);
+    let mut rows = array.rows_iter_mut().collect::<Vec<_>>();
+    // Rows can be interleaved because they never overlap.
+    let mut last = rows.pop().unwrap();
+    for element in rows[0].by_ref() {
+        *element = 1;
+    }
+    *last.next().unwrap() = 2;
+    drop((rows, last));
+    assert_eq!(array.as_rows(), vec![vec![1, 1], vec![0, 0], vec![2, 0]]);
+    Ok(())
+}
+
+#[test]
+fn test_columns_iter_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    for (column_index, column) in array.columns_iter_mut().enumerate() {
+        for (row_index, element) in column.enumerate() {
+            *element = row_index * 10 + column_index;
+        }
+    }
+    assert_eq!(array.as_rows(), vec![vec![0, 1, 2], vec![10, 11, 12]]);
+    Ok(())
+}
+
+#[test]
+fn test_enumerate_row_major_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(2, 2, (0, 0));
+    for (index, element) in array.enumerate_row_major_mut() {
+        *element = index;
+    }
+    assert_eq!(array.as_row_major(), vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    Ok(())
+}
+
+#[test]
+fn test_double_ended_iterator_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    for (element, value) in array.elements_row_major_iter_mut().rev().zip(1..) {
+        *element = value;
+    }
+    assert_eq!(array.as_rows(), vec![vec![6, 5, 4], vec![3, 2, 1]]);
+    for (element, value) in array.elements_column_major_iter_mut().rev().zip(1..) {
+        *element = value;
+    }
+    assert_eq!(array.as_rows(), vec![vec![6, 4, 2], vec![5, 3, 1]]);
+    for (element, value) in array.row_iter_mut(1)?.rev().zip(10..) {
+        *element = value;
+    }
+    for (element, value) in array.column_iter_mut(0)?.rev().zip(20..) {
+        *element = value;
+    }
+    assert_eq!(array.as_rows(), vec![vec![21, 4, 2], vec![20, 11, 10]]);
+    for (row, value) in array.rows_iter_mut().rev().zip(30..) {
+        for element in row {
+            *element = value;
+        }
+    }
+    assert_eq!(array.as_rows(), vec![vec![31, 31, 31], vec![30, 30, 30]]);
+    for (column, value) in array.columns_iter_mut().rev().zip(40..) {
+        for element in column {
+            *element = value;
+        }
+    }
+    assert_eq!(array.as_rows(), vec![vec![42, 41, 40], vec![42, 41, 40]]);
+    let last = array.enumerate_row_major_mut().next_back().unwrap();
+    assert_eq!(last, ((1, 2), &mut 40));
+    Ok(())
+}
+
+#[test]
+fn test_iter_mut_out_of_bounds() {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    assert!(array.row_iter_mut(2).is_err());
+    assert!(array.column_iter_mut(3).is_err());
+}
+
+#[test]
+fn test_rows_iter_mut_no_columns() {
+    let mut array: Array2D<i32> = Array2D::filled_with(3, 0, 0);
+    let lengths = array
+        .rows_iter_mut()
+        .map(|row| row.count())
+        .collect::<Vec<_>>();
+    assert_eq!(lengths, vec![0, 0, 0]);
+    assert_eq!(array.columns_iter_mut().count(), 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Iterator Types //////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+struct Cursor<'a> {
+    columns: ColumnsIter<'a, i32>,
+}
+
+#[test]
+fn test_named_iterator_types() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    let mut cursor = Cursor {
+        columns: array.columns_iter(),
+    };
+    let column: ColumnIter<i32> = cursor.columns.next_back().unwrap();
+    assert_eq!(column.copied().collect::<Vec<_>>(), vec![3, 6]);
+    let row: RowIter<i32> = array.row_iter(1)?;
+    assert_eq!(row.copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+    Ok(())
+}
+
+#[test]
+fn test_exact_size_iterators() -> Result<(), Error> {
+    let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(array.row_iter(0)?.len(), 3);
+    assert_eq!(array.column_iter(0)?.len(), 2);
+    assert_eq!(array.rows_iter().len(), 2);
+    assert_eq!(array.columns_iter().len(), 3);
+    assert_eq!(array.elements_row_major_iter().len(), 6);
+    assert_eq!(array.elements_column_major_iter().len(), 6);
+    assert_eq!(array.indices_row_major().len(), 6);
+    assert_eq!(array.indices_column_major().len(), 6);
+    assert_eq!(array.enumerate_row_major().len(), 6);
+    assert_eq!(array.enumerate_column_major().len(), 6);
+    assert_eq!(array.row_iter_mut(0)?.len(), 3);
+    assert_eq!(array.column_iter_mut(0)?.len(), 2);
+    assert_eq!(array.rows_iter_mut().len(), 2);
+    assert_eq!(array.columns_iter_mut().len(), 3);
+    assert_eq!(array.elements_row_major_iter_mut().len(), 6);
+    assert_eq!(array.elements_column_major_iter_mut().len(), 6);
+    assert_eq!(array.enumerate_row_major_mut().len(), 6);
+    Ok(())
+}
+
+#[test]
+fn test_iterator_size_hint_from_both_ends() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    let mut column_major = array.elements_column_major_iter();
+    assert_eq!(column_major.size_hint(), (6, Some(6)));
+    assert_eq!(column_major.next(), Some(&1));
+    assert_eq!(column_major.next_back(), Some(&6));
+    assert_eq!(column_major.size_hint(), (4, Some(4)));
+    assert_eq!(
+        column_major.clone().collect::<Vec<_>>(),
+        vec![&4, &2, &5, &3]
+    );
+    assert_eq!(column_major.nth(2), Some(&5));
+    assert_eq!(column_major.len(), 1);
+    assert_eq!(column_major.nth(1), None);
+    assert_eq!(column_major.next(), None);
+    assert_eq!(column_major.next_back(), None);
+
+    let mut enumerate = array.enumerate_row_major();
+    assert_eq!(enumerate.next_back(), Some(((1, 2), &6)));
+    assert_eq!(enumerate.nth(1), Some(((0, 1), &2)));
+    assert_eq!(enumerate.next_back(), Some(((1, 1), &5)));
+    assert_eq!(enumerate.len(), 2);
+    assert_eq!(enumerate.next(), Some(((0, 2), &3)));
+    assert_eq!(enumerate.next_back(), Some(((1, 0), &4)));
+    assert_eq!(enumerate.next(), None);
+
+    let mut indices = array.indices_column_major();
+    assert_eq!(indices.nth(4), Some((0, 2)));
+    assert_eq!(indices.next_back(), Some((1, 2)));
+    assert_eq!(indices.size_hint(), (0, Some(0)));
+    Ok(())
+}
+
+#[test]
+fn test_iterators_over_empty_dimensions() {
+    let array = Array2D::filled_with(3, 0, 0);
+    assert_eq!(array.rows_iter().len(), 3);
+    assert!(array.rows_iter().all(|row| row.len() == 0));
+    assert_eq!(array.columns_iter().len(), 0);
+    assert_eq!(array.indices_column_major().len(), 0);
+    let view = array.view();
+    assert!(view.rows_iter().all(|row| row.len() == 0));
+    assert_eq!(view.row_iter(2).map(|row| row.len()), Ok(0));
+}
+
+#[test]
+fn test_view_strided_rows_and_columns() -> Result<(), Error> {
+    let array = Array2D::from_row_major(4, 5, &(0..20).collect::<Vec<_>>())?;
+    let view = array.step_by_offset((1, 1), 2, 2);
+    let rows = view
+        .rows_iter()
+        .map(|row| row.copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![vec![6, 8], vec![16, 18]]);
+    let mut columns = view.columns_iter();
+    assert_eq!(columns.len(), 2);
+    let last = columns.next_back().unwrap();
+    assert_eq!(last.rev().copied().collect::<Vec<_>>(), vec![18, 8]);
+    assert_eq!(view.column_iter(0)?.len(), 2);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Neighbors ///////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_neighbors4() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 3, 0..)?;
+    let center = array.neighbors4((1, 1))?.collect::<Vec<_>>();
+    assert_eq!(
+        center,
+        vec![((0, 1), &1), ((1, 0), &3), ((1, 2), &5), ((2, 1), &7)]
+    );
+    let corner = array.neighbors4((2, 2))?.rev().collect::<Vec<_>>();
+    assert_eq!(corner, vec![((2, 1), &7), ((1, 2), &5)]);
+    Ok(())
+}
+
+#[test]
+fn test_neighbors8() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 3, 0..)?;
+    let center = array
+        .neighbors8((1, 1))?
+        .map(|(_, &x)| x)
+        .collect::<Vec<_>>();
+    assert_eq!(center, vec![0, 1, 2, 3, 5, 6, 7, 8]);
+    let edge = array.neighbors8_indices((0, 1))?.collect::<Vec<_>>();
+    assert_eq!(edge, vec![(0, 0), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    let single = Array2D::filled_with(1, 1, 0);
+    assert_eq!(single.neighbors8((0, 0))?.next(), None);
+    Ok(())
+}
+
+#[test]
+fn test_neighbors_with() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(5, 5, 0..)?;
+    let offsets = [(0, 0), (-2, 0), (0, 2), (isize::MIN, 0), (0, isize::MAX)];
+    let neighbors = array.neighbors_with((1, 1), &offsets)?;
+    assert_eq!(neighbors.size_hint(), (0, Some(5)));
+    assert_eq!(
+        neighbors.collect::<Vec<_>>(),
+        vec![((1, 1), &6), ((1, 3), &8)]
+    );
+    let indices = array.neighbors_with_indices((4, 4), &[(1, 0), (-4, -4)])?;
+    assert_eq!(indices.collect::<Vec<_>>(), vec![(0, 0)]);
+    Ok(())
+}
+
+#[test]
+fn test_neighbors_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(3, 4, 0);
+    for (_, element) in array.neighbors8_mut((0, 0))? {
+        *element += 1;
+    }
+    for ((row, column), element) in array.neighbors4_mut((2, 3))?.rev() {
+        *element += row * 10 + column;
+    }
+    for (_, element) in array.neighbors_with_mut((1, 1), &[(1, 1), (-5, 0), (-5, 0)])? {
+        *element += 100;
+    }
+    assert_eq!(
+        array.as_rows(),
+        vec![vec![0, 1, 0, 0], vec![1, 1, 0, 13], vec![0, 0, 122, 0]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_neighbors_out_of_bounds() {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    let error = Err(Error::IndicesOutOfBounds {
+        row: 2,
+        column: 0,
+        num_rows: 2,
+        num_columns: 3,
+    });
+    assert_eq!(array.neighbors4((2, 0)).map(|n| n.count()), error);
+    assert_eq!(array.neighbors8_indices((2, 0)).map(|n| n.count()), error);
+    assert_eq!(array.neighbors8_mut((2, 0)).map(|n| n.count()), error);
+    let empty = Array2D::<i32>::filled_with(0, 0, 0);
+    assert!(empty.neighbors4((0, 0)).is_err());
+}
+
+#[test]
+fn test_neighbors_with_mut_duplicate_offsets() {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    let result = array.neighbors_with_mut((0, 0), &[(1, 1), (0, 1), (1, 1)]);
+    assert_eq!(
+        result.err(),
+        Some(Error::DuplicateIndices { row: 1, column: 1 })
+    );
+}
+
+#[test]
+fn test_neighbors_with_mut_duplicate_offsets_out_of_bounds() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(2, 3, 0);
+    let neighbors = array.neighbors_with_mut((0, 0), &[(-1, 0), (0, 1), (-1, 0)])?;
+    assert_eq!(neighbors.count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_neighbors_wrapping() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 4, 0..)?;
+    let neighbors = array.neighbors4_wrapping((0, 3))?.collect::<Vec<_>>();
+    assert_eq!(
+        neighbors,
+        vec![((2, 3), &11), ((0, 2), &2), ((0, 0), &0), ((1, 3), &7)]
+    );
+    let indices = array
+        .neighbors8_wrapping_indices((2, 0))?
+        .collect::<Vec<_>>();
+    assert_eq!(
+        indices,
+        vec![
+            (1, 3),
+            (1, 0),
+            (1, 1),
+            (2, 3),
+            (2, 1),
+            (0, 3),
+            (0, 0),
+            (0, 1)
+        ]
+    );
+    assert_eq!(array.neighbors8_wrapping((1, 1))?.count(), 8);
+    assert_eq!(
+        array.neighbors8_wrapping((1, 1))?.collect::<Vec<_>>(),
+        array.neighbors8((1, 1))?.collect::<Vec<_>>()
+    );
+    assert_eq!(
+        array
+            .neighbors4_wrapping_indices((0, 0))?
+            .rev()
+            .collect::<Vec<_>>(),
+        vec![(1, 0), (0, 1), (0, 3), (2, 0)]
+    );
+
+    let offsets = [(-7, 0), (0, 9), (isize::MIN, isize::MAX)];
+    let indices = array
+        .neighbors_with_wrapping_indices((1, 1), &offsets)?
+        .collect::<Vec<_>>();
+    assert_eq!(
+        indices,
+        vec![
+            (wrapped_row(1, -7, 3), 1),
+            (1, 2),
+            (wrapped_row(1, isize::MIN, 3), 0)
+        ]
+    );
+    Ok(())
+}
+
+fn wrapped_row(row: usize, offset: isize, num_rows: usize) -> usize {
+    (row as i128 + offset as i128).rem_euclid(num_rows as i128) as usize
+}
+
+#[test]
+fn test_neighbors_wrapping_mut() -> Result<(), Error> {
+    let mut array = Array2D::filled_with(3, 3, 0);
+    for (_, element) in array.neighbors8_wrapping_mut((0, 0))? {
+        *element += 1;
+    }
+    assert_eq!(
+        array.as_rows(),
+        vec![vec![0, 1, 1], vec![1, 1, 1], vec![1, 1, 1]]
+    );
+    for (_, element) in array.neighbors_with_wrapping_mut((1, 1), &[(0, 2), (5, 0)])? {
+        *element = 9;
+    }
+    assert_eq!(
+        array.as_rows(),
+        vec![vec![0, 9, 1], vec![9, 1, 1], vec![1, 1, 1]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_neighbors_wrapping_mut_duplicate_indices() {
+    let mut narrow = Array2D::filled_with(3, 1, 0);
+    let result = narrow.neighbors4_wrapping_mut((1, 0));
+    assert_eq!(
+        result.err(),
+        Some(Error::DuplicateIndices { row: 1, column: 0 })
+    );
+    let mut small = Array2D::filled_with(2, 2, 0);
+    let result = small.neighbors8_wrapping_mut((0, 0));
+    assert_eq!(
+        result.err(),
+        Some(Error::DuplicateIndices { row: 1, column: 1 })
+    );
+    let result = small.neighbors_with_wrapping_mut((0, 0), &[(0, 1), (0, 3)]);
+    assert_eq!(
+        result.err(),
+        Some(Error::DuplicateIndices { row: 0, column: 1 })
+    );
+    assert_eq!(
+        small.neighbors4_wrapping((2, 0)).map(|n| n.count()),
+        Err(Error::IndicesOutOfBounds {
+            row: 2,
+            column: 0,
+            num_rows: 2,
+            num_columns: 2
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Border, Ring and Spiral /////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+fn spiral_indices(num_rows: usize, num_columns: usize) -> Vec<(usize, usize)> {
+    if num_rows == 0 || num_columns == 0 {
+        return Vec::new();
+    }
+    let mut indices = Vec::new();
+    let (mut top, mut left) = (0, 0);
+    let (mut bottom, mut right) = (num_rows as isize - 1, num_columns as isize - 1);
+    while top <= bottom && left <= right {
+        for column in left..=right {
+            indices.push((top, column));
+        }
+        for row in top + 1..=bottom {
+            indices.push((row, right));
+        }
+        if top < bottom && left < right {
+            for column in (left..right).rev() {
+                indices.push((bottom, column));
+            }
+            for row in (top + 1..bottom).rev() {
+                indices.push((row, left));
+            }
+        }
+        top += 1;
+        left += 1;
+        bottom -= 1;
+        right -= 1;
+    }
+    indices
+        .into_iter()
+        .map(|(row, column)| (row as usize, column as usize))
+        .collect()
+}
+
+#[test]
+fn test_border_iter() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 4, 0..)?;
+    let border = array.border_iter().map(|(_, &x)| x).collect::<Vec<_>>();
+    assert_eq!(border, vec![0, 1, 2, 3, 7, 11, 10, 9, 8, 4]);
+    let reversed = array
+        .border_iter()
+        .rev()
+        .map(|(_, &x)| x)
+        .collect::<Vec<_>>();
+    assert_eq!(reversed, border.iter().rev().copied().collect::<Vec<_>>());
+    assert_eq!(array.border_iter().len(), 10);
+    Ok(())
+}
+
+#[test]
+fn test_border_iter_thin_arrays() -> Result<(), Error> {
+    let row = Array2D::from_iter_row_major(1, 4, 0..)?;
+    let border = row.border_iter().map(|(_, &x)| x).collect::<Vec<_>>();
+    assert_eq!(border, vec![0, 1, 2, 3]);
+    let column = Array2D::from_iter_row_major(4, 1, 0..)?;
+    let border = column.border_iter().map(|(_, &x)| x).collect::<Vec<_>>();
+    assert_eq!(border, vec![0, 1, 2, 3]);
+    let square = Array2D::from_iter_row_major(2, 2, 0..)?;
+    let border = square.border_iter().map(|(_, &x)| x).collect::<Vec<_>>();
+    assert_eq!(border, vec![0, 1, 3, 2]);
+    for (num_rows, num_columns) in [(0, 0), (0, 3), (3, 0)] {
+        let empty = Array2D::filled_with(num_rows, num_columns, 0);
+        assert_eq!(empty.border_iter().next(), None);
+        assert_eq!(empty.spiral_iter().next(), None);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_ring_iter() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(5, 6, 0..)?;
+    for (row, column) in array.indices_row_major() {
+        for k in 0..8 {
+            let ring = array
+                .ring_iter((row, column), k)?
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            let mut sorted = ring.clone();
+            sorted.sort();
+            let expected = array
+                .indices_row_major()
+                .filter(|&(r, c)| r.abs_diff(row).max(c.abs_diff(column)) == k)
+                .collect::<Vec<_>>();
+            assert_eq!(sorted, expected);
+            let reversed = array
+                .ring_iter((row, column), k)?
+                .rev()
+                .map(|(index, _)| index);
+            assert!(reversed.eq(ring.into_iter().rev()));
+        }
+    }
+    assert_eq!(array.ring_iter((2, 2), usize::MAX)?.len(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_ring_iter_order() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(5, 5, 0..)?;
+    let ring = array
+        .ring_iter((2, 2), 2)?
+        .map(|(_, &x)| x)
+        .collect::<Vec<_>>();
+    let border = array.border_iter().map(|(_, &x)| x).collect::<Vec<_>>();
+    assert_eq!(ring, border);
+    let clipped = array
+        .ring_iter((4, 1), 1)?
+        .map(|(_, &x)| x)
+        .collect::<Vec<_>>();
+    assert_eq!(clipped, vec![15, 16, 17, 22, 20]);
+    Ok(())
+}
+
+#[test]
+fn test_spiral_iter() -> Result<(), Error> {
+    for num_rows in 0..7 {
+        for num_columns in 0..7 {
+            let array = Array2D::filled_with(num_rows, num_columns, ());
+            let expected = spiral_indices(num_rows, num_columns);
+            let spiral = array
+                .spiral_iter()
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            assert_eq!(spiral, expected);
+            let reversed = array.spiral_iter().rev().map(|(index, _)| index);
+            assert!(reversed.eq(expected.iter().rev().copied()));
+            assert_eq!(array.spiral_iter().len(), num_rows * num_columns);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_spiral_iter_from_both_ends() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(4, 5, 0..)?;
+    let mut spiral = array.spiral_iter();
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+    while let Some((_, &x)) = spiral.next() {
+        front.push(x);
+        if let Some((_, &x)) = spiral.next_back() {
+            back.push(x);
+        }
+    }
+    front.extend(back.into_iter().rev());
+    assert_eq!(
+        front,
+        vec![0, 1, 2, 3, 4, 9, 14, 19, 18, 17, 16, 15, 10, 5, 6, 7, 8, 13, 12, 11]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ring_iter_out_of_bounds() {
+    let array = Array2D::filled_with(2, 3, 0);
+    let result = array.ring_iter((1, 3), 1).map(|ring| ring.count());
+    assert_eq!(
+        result,
+        Err(Error::IndicesOutOfBounds {
+            row: 1,
+            column: 3,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traversals //////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+const TRAVERSALS: [Traversal; 5] = [
+    Traversal::RowMajor,
+    Traversal::ColumnMajor,
+    Traversal::Snake,
+    Traversal::Morton,
+    Traversal::Hilbert,
+];
+
+#[test]
+fn test_traversal_visits_every_index_once() {
+    for traversal in TRAVERSALS {
+        for num_rows in 0..10 {
+            for num_columns in 0..10 {
+                let array = Array2D::filled_with(num_rows, num_columns, ());
+                let indices = array.indices(traversal).collect::<Vec<_>>();
+                assert_eq!(array.indices(traversal).len(), indices.len());
+                let mut sorted = indices.clone();
+                sorted.sort();
+                assert_eq!(sorted, array.indices_row_major().collect::<Vec<_>>());
+                let reversed = array.indices(traversal).rev();
+                assert!(reversed.eq(indices.into_iter().rev()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_traversal_row_and_column_major() {
+    let array = Array2D::filled_with(3, 5, ());
+    assert!(array
+        .indices(Traversal::RowMajor)
+        .eq(array.indices_row_major()));
+    assert!(array
+        .indices(Traversal::ColumnMajor)
+        .eq(array.indices_column_major()));
+}
+
+#[test]
+fn test_traversal_curves_are_continuous() {
+    for (num_rows, num_columns) in [(1, 7), (7, 1), (4, 4), (5, 3), (8, 16), (16, 8)] {
+        let array = Array2D::filled_with(num_rows, num_columns, ());
+        let indices = array.indices(Traversal::Snake).collect::<Vec<_>>();
+        for pair in indices.windows(2) {
+            let ((row1, column1), (row2, column2)) = (pair[0], pair[1]);
+            assert_eq!(row1.abs_diff(row2) + column1.abs_diff(column2), 1);
+        }
+    }
+    for (num_rows, num_columns) in [(1, 1), (2, 2), (8, 8), (4, 16), (16, 4), (1, 5)] {
+        let array = Array2D::filled_with(num_rows, num_columns, ());
+        let indices = array.indices(Traversal::Hilbert).collect::<Vec<_>>();
+        assert_eq!(indices[0], (0, 0));
+        for pair in indices.windows(2) {
+            let ((row1, column1), (row2, column2)) = (pair[0], pair[1]);
+            assert_eq!(row1.abs_diff(row2) + column1.abs_diff(column2), 1);
+        }
+    }
+}
+
+#[test]
+fn test_traversal_morton() {
+    let array = Array2D::filled_with(4, 4, ());
+    let indices = array.indices(Traversal::Morton).take(8).collect::<Vec<_>>();
+    assert_eq!(
+        indices,
+        vec![
+            (0, 0),
+            (0, 1),
+            (1, 0),
+            (1, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3)
+        ]
+    );
+    let wide = Array2D::filled_with(2, 4, ());
+    let indices = wide.indices(Traversal::Morton).collect::<Vec<_>>();
+    assert_eq!(
+        indices,
+        vec![
+            (0, 0),
+            (0, 1),
+            (1, 0),
+            (1, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3)
+        ]
+    );
+}
+
+#[test]
+fn test_from_iter_and_enumerate_with_traversal() -> Result<(), Error> {
+    for traversal in TRAVERSALS {
+        let array = Array2D::from_iter(5, 7, traversal, 0..)?;
+        let visited = array.enumerate(traversal).map(|(_, &x)| x);
+        assert!(visited.eq(0..35));
+        for (index, &element) in array.enumerate(traversal).rev() {
+            assert_eq!(array[index], element);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_map_with_traversal() -> Result<(), Error> {
+    let array = Array2D::from_iter_row_major(3, 5, 0..)?;
+    for traversal in TRAVERSALS {
+        let mut visited = Vec::new();
+        let mapped = array.map(traversal, |&x| {
+            visited.push(x);
+            x * 2
+        });
+        assert_eq!(mapped, array.map_row_major(|x| x * 2));
+        let expected = array
+            .enumerate(traversal)
+            .map(|(_, &x)| x)
+            .collect::<Vec<_>>();
+        assert_eq!(visited, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_from_iter_with_traversal_not_enough_elements() {
+    for traversal in TRAVERSALS {
+        let result = Array2D::from_iter(3, 3, traversal, 0..8);
+        assert_eq!(
+            result,
+            Err(Error::NotEnoughElements {
+                expected: 9,
+                actual: 8
+            })
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Zip /////////////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_zip_with() -> Result<(), Error> {
+    let terrain = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    let occupied = Array2D::from_rows(&[vec![false, true, false], vec![true, false, false]])?;
+    let cost = terrain.zip_with(
+        &occupied,
+        |&cost, &occupied| if occupied { 0 } else { cost },
+    )?;
+    assert_eq!(cost.as_rows(), vec![vec![1, 0, 3], vec![0, 5, 6]]);
+    Ok(())
+}
+
+#[test]
+fn test_zip_with_dimension_mismatch() -> Result<(), Error> {
+    let array = Array2D::filled_with(2, 3, 0);
+    let transposed = Array2D::filled_with(3, 2, 0);
+    let error = Some(Error::ShapeMismatch {
+        expected: (2, 3),
+        actual: (3, 2),
+    });
+    assert_eq!(array.zip_with(&transposed, |a, b| a + b).err(), error);
+    assert_eq!(array.zip(&transposed).err(), error);
+    Ok(())
+}
+
+#[test]
+fn test_zip() -> Result<(), Error> {
+    let numbers = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    let letters = Array2D::from_rows(&[vec!['a', 'b', 'c'], vec!['d', 'e', 'f']])?;
+    let zipped = numbers.zip(&letters)?.collect::<Vec<_>>();
+    assert_eq!(
+        zipped,
+        vec![
+            ((0, 0), (&1, &'a')),
+            ((0, 1), (&2, &'b')),
+            ((0, 2), (&3, &'c')),
+            ((1, 0), (&4, &'d')),
+            ((1, 1), (&5, &'e')),
+            ((1, 2), (&6, &'f')),
+        ]
+    );
+
+    let mut zip = numbers.zip(&letters)?;
+    assert_eq!(zip.len(), 6);
+    assert_eq!(zip.next_back(), Some(((1, 2), (&6, &'f'))));
+    assert_eq!(zip.next(), Some(((0, 0), (&1, &'a'))));
+    assert_eq!(zip.len(), 4);
+    assert_eq!(zip.clone().next_back(), Some(((1, 1), (&5, &'e'))));
+    assert_eq!(zip.count(), 4);
+
+    let empty = Array2D::<i32>::filled_with(0, 3, 0);
+    assert_eq!(empty.zip(&empty)?.next(), None);
+    Ok(())
+}
+
+#[test]
+fn test_zip_apply() -> Result<(), Error> {
+    let mut totals = Array2D::from_rows(&[vec![1, 2], vec![3, 4]])?;
+    let increments = Array2D::from_rows(&[vec![10, 20], vec![30, 40]])?;
+    totals.zip_apply(&increments, |total, increment| *total += increment)?;
+    assert_eq!(totals.as_rows(), vec![vec![11, 22], vec![33, 44]]);
+
+    let wrong_size = Array2D::filled_with(1, 4, 0);
+    assert_eq!(
+        totals.zip_apply(&wrong_size, |total, increment| *total += increment),
+        Err(Error::ShapeMismatch {
+            expected: (2, 2),
+            actual: (1, 4)
+        })
+    );
+    assert_eq!(totals.as_rows(), vec![vec![11, 22], vec![33, 44]]);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Row and Column Summaries ////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_fold_rows_and_columns() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(
+        array.fold_rows(0, |total, element| total + element),
+        vec![6, 15]
+    );
+    assert_eq!(
+        array.fold_columns(0, |total, element| total + element),
+        vec![5, 7, 9]
+    );
+    let digits = array.fold_rows(String::new(), |mut digits, element| {
+        digits.push_str(&element.to_string());
+        digits
+    });
+    assert_eq!(digits, vec!["123", "456"]);
+    Ok(())
+}
+
+#[test]
+fn test_reduce_rows_and_columns() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![1, 8, 3], vec![7, 5, 6]])?;
+    assert_eq!(
+        array.reduce_rows(|max, &element| max.max(element)),
+        vec![Some(8), Some(7)]
+    );
+    assert_eq!(
+        array.reduce_columns(|max, &element| max.max(element)),
+        vec![Some(7), Some(8), Some(6)]
+    );
+    assert_eq!(array.reduce_rows(|first, _| first), vec![Some(1), Some(7)]);
+    Ok(())
+}
+
+#[test]
+fn test_map_rows_and_columns() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![3, 1, 2], vec![4, 6, 5]])?;
+    assert_eq!(
+        array.map_rows(|row| row.copied().collect::<Vec<_>>()),
+        array.as_rows()
+    );
+    assert_eq!(
+        array.map_columns(|column| column.copied().collect::<Vec<_>>()),
+        array.as_columns()
+    );
+    assert_eq!(array.map_rows(|row| row.len()), vec![3, 3]);
+    assert_eq!(array.map_columns(|column| column.len()), vec![2, 2, 2]);
+    Ok(())
+}
+
+#[test]
+fn test_summaries_as_arrays() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    let row_totals = array.fold_rows_as_column(0, |total, element| total + element);
+    assert_eq!(row_totals.num_rows(), 2);
+    assert_eq!(row_totals.num_columns(), 1);
+    assert_eq!(row_totals.as_rows(), vec![vec![6], vec![15]]);
+
+    let column_totals = array.fold_columns_as_row(0, |total, element| total + element);
+    assert_eq!(column_totals.num_rows(), 1);
+    assert_eq!(column_totals.num_columns(), 3);
+    assert_eq!(column_totals.as_rows(), vec![vec![5, 7, 9]]);
+
+    let row_maxima = array.reduce_rows_as_column(|max, &element| max.max(element));
+    assert_eq!(row_maxima.as_rows(), vec![vec![Some(3)], vec![Some(6)]]);
+    let column_maxima = array.reduce_columns_as_row(|max, &element| max.max(element));
+    assert_eq!(
+        column_maxima.as_rows(),
+        vec![vec![Some(4), Some(5), Some(6)]]
+    );
+
+    let first = array.map_rows_as_column(|mut row| row.next().copied());
+    assert_eq!(first.as_columns(), vec![vec![Some(1), Some(4)]]);
+    let last = array.map_columns_as_row(|mut column| column.next_back().copied());
+    assert_eq!(last.as_rows(), vec![vec![Some(4), Some(5), Some(6)]]);
+    Ok(())
+}
+
+#[test]
+fn test_summaries_of_empty_lines() -> Result<(), Error> {
+    let no_columns = Array2D::filled_with(2, 0, 1);
+    assert_eq!(
+        no_columns.fold_rows(0, |total, element| total + element),
+        vec![0, 0]
+    );
+    assert_eq!(
+        no_columns.reduce_rows(|total, element| total + element),
+        vec![None, None]
+    );
+    assert_eq!(no_columns.map_rows(|row| row.len()), vec![0, 0]);
+    assert!(no_columns
+        .fold_columns(0, |total, element| total + element)
+        .is_empty());
+    assert_eq!(
+        no_columns.map_rows_as_column(|row| row.len()).as_rows(),
+        vec![vec![0], vec![0]]
+    );
+
+    let no_rows = Array2D::filled_with(0, 3, 1);
+    assert_eq!(
+        no_rows.fold_columns(0, |total, element| total + element),
+        vec![0, 0, 0]
+    );
+    assert_eq!(
+        no_rows.reduce_columns(|total, element| total + element),
+        vec![None, None, None]
+    );
+    assert!(no_rows.map_rows(|row| row.len()).is_empty());
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Searching ///////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_position() -> Result<(), Error> {
+    let board = Array2D::from_rows(&[vec!['.', '.', '@'], vec!['@', '.', '@']])?;
+    let is_player = |&tile: &char| tile == '@';
+    assert_eq!(board.position_row_major(is_player), Some((0, 2)));
+    assert_eq!(board.position_column_major(is_player), Some((1, 0)));
+    assert_eq!(board.rposition_row_major(is_player), Some((1, 2)));
+    assert_eq!(board.rposition_column_major(is_player), Some((1, 2)));
+    assert_eq!(
+        board.rposition_column_major(|&tile| tile == '.'),
+        Some((1, 1))
+    );
+
+    let is_wall = |&tile: &char| tile == '#';
+    assert_eq!(board.position_row_major(is_wall), None);
+    assert_eq!(board.position_column_major(is_wall), None);
+    assert_eq!(board.rposition_row_major(is_wall), None);
+    assert_eq!(board.rposition_column_major(is_wall), None);
+    Ok(())
+}
+
+#[test]
+fn test_positions() -> Result<(), Error> {
+    let board = Array2D::from_rows(&[vec!['.', '@', '@'], vec!['@', '.', '.']])?;
+    let players = board.positions(|&tile| tile == '@');
+    assert_eq!(
+        players.clone().collect::<Vec<_>>(),
+        vec![(0, 1), (0, 2), (1, 0)]
+    );
+    assert_eq!(
+        players.rev().collect::<Vec<_>>(),
+        vec![(1, 0), (0, 2), (0, 1)]
+    );
+
+    let mut players = board.positions(|&tile| tile == '@');
+    assert_eq!(players.size_hint(), (0, Some(6)));
+    assert_eq!(players.next(), Some((0, 1)));
+    assert_eq!(players.next_back(), Some((1, 0)));
+    assert_eq!(players.next(), Some((0, 2)));
+    assert_eq!(players.next(), None);
+    assert_eq!(players.next_back(), None);
+
+    assert_eq!(board.positions(|&tile| tile == '#').next(), None);
+    Ok(())
+}
+
+#[test]
+fn test_contains_and_count() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert!(array.contains(&1));
+    assert!(array.contains(&6));
+    assert!(!array.contains(&0));
+    assert_eq!(array.count(|element| element % 2 == 0), 3);
+    assert_eq!(array.count(|&element| element > 10), 0);
+    assert_eq!(array.count(|_| true), array.num_elements());
+    Ok(())
+}
+
+#[test]
+fn test_min_and_max_by_key() -> Result<(), Error> {
+    let heights = Array2D::from_rows(&[vec![3, 1, 9], vec![1, 5, 9]])?;
+    assert_eq!(heights.min_by_key(|&height| height), Some(((0, 1), &1)));
+    assert_eq!(heights.max_by_key(|&height| height), Some(((1, 2), &9)));
+    assert_eq!(
+        heights.min_by_key(|&height| (height - 5_i32).abs()),
+        Some(((1, 1), &5))
+    );
+
+    let empty = Array2D::<i32>::filled_with(0, 0, 0);
+    assert_eq!(empty.min_by_key(|&height| height), None);
+    assert_eq!(empty.max_by_key(|&height| height), None);
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Pattern Matching ////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+fn find_pattern_naively<T: PartialEq>(
+    array: &Array2D<T>,
+    pattern: &Array2D<Option<T>>,
+) -> Vec<(usize, usize)> {
+    let mut matches = Vec::new();
+    if pattern.num_rows() == 0 || pattern.num_columns() == 0 {
+        return matches;
+    }
+    for row in 0..array.num_rows() {
+        for column in 0..array.num_columns() {
+            let found = pattern.enumerate_row_major().all(
+                |((pattern_row, pattern_column), element)| match (
+                    array.get((row + pattern_row, column + pattern_column)),
+                    element,
+                ) {
+                    (Some(actual), Some(element)) => actual == element,
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                },
+            );
+            if found {
+                matches.push((row, column));
+            }
+        }
+    }
+    matches
+}
+
+fn pseudo_random_array(num_rows: usize, num_columns: usize, num_values: u32) -> Array2D<u32> {
+    let mut state = 0x2545_f491_u32;
+    Array2D::filled_by_row_major(num_rows, num_columns, || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state % num_values
+    })
+}
+
+#[test]
+fn test_oriented() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    let rotated = array.oriented(Orientation::Rotate90);
+    assert_eq!(rotated.num_rows(), 3);
+    assert_eq!(rotated.num_columns(), 2);
+    assert_eq!(
+        rotated.oriented(Orientation::Rotate90),
+        array.oriented(Orientation::Rotate180)
+    );
+    assert_eq!(
+        rotated.oriented(Orientation::Rotate180),
+        array.oriented(Orientation::Rotate270)
+    );
+    assert_eq!(
+        array.oriented(Orientation::Transpose).as_rows(),
+        array.as_columns()
+    );
+    assert_eq!(
+        array
+            .oriented(Orientation::FlipHorizontal)
+            .oriented(Orientation::FlipVertical),
+        array.oriented(Orientation::Rotate180)
+    );
+    assert_eq!(
+        array
+            .oriented(Orientation::Transpose)
+            .oriented(Orientation::Rotate180),
+        array.oriented(Orientation::AntiTranspose)
+    );
+    let all = Orientation::ALL
+        .iter()
+        .map(|&orientation| array.oriented(orientation))
+        .collect::<Vec<_>>();
+    for (index, oriented) in all.iter().enumerate() {
+        assert!(!all[..index].contains(oriented));
+    }
+
+    let empty = Array2D::<i32>::filled_with(0, 3, 0);
+    assert_eq!(empty.oriented(Orientation::Rotate90).num_rows(), 3);
+    assert_eq!(empty.oriented(Orientation::Rotate90).num_columns(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_find_pattern() -> Result<(), Error> {
+    let board = Array2D::from_rows(&[vec![1, 1, 1, 1], vec![1, 1, 1, 0], vec![1, 1, 1, 1]])?;
+    let square = Array2D::filled_with(2, 2, 1);
+    assert_eq!(
+        board.find_pattern(&square).collect::<Vec<_>>(),
+        vec![(0, 0), (0, 1), (1, 0), (1, 1)]
+    );
+    let mut matches = board.find_pattern(&square);
+    assert_eq!(matches.len(), 4);
+    assert_eq!(matches.next_back(), Some((1, 1)));
+    assert_eq!(matches.next(), Some((0, 0)));
+    assert_eq!(matches.len(), 2);
+
+    assert_eq!(board.find_pattern(&board).collect::<Vec<_>>(), vec![(0, 0)]);
+    let too_big = Array2D::filled_with(4, 1, 1);
+    assert_eq!(board.find_pattern(&too_big).next(), None);
+    let empty = Array2D::filled_with(0, 2, 1);
+    assert_eq!(board.find_pattern(&empty).next(), None);
+    Ok(())
+}
+
+#[test]
+fn test_find_pattern_matches_naive_search() -> Result<(), Error> {
+    let array = pseudo_random_array(23, 31, 2);
+    for &(num_rows, num_columns) in &[(1, 1), (1, 4), (3, 1), (2, 2), (3, 4), (5, 3)] {
+        for &(row, column) in &[(0, 0), (7, 11), (23 - num_rows, 31 - num_columns)] {
+            let rows = (row..row + num_rows)
+                .map(|r| {
+                    (column..column + num_columns)
+                        .map(|c| array[(r, c)])
+                        .collect()
+                })
+                .collect::<Vec<_>>();
+            let pattern = Array2D::from_rows(&rows)?;
+            let expected =
+                find_pattern_naively(&array, &pattern.map_row_major(|&element| Some(element)));
+            assert!(expected.contains(&(row, column)));
+            assert_eq!(array.find_pattern(&pattern).collect::<Vec<_>>(), expected);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_find_pattern_with_wildcards() -> Result<(), Error> {
+    let board = Array2D::from_rows(&[
+        vec!['#', '#', '.'],
+        vec!['#', '#', '#'],
+        vec!['.', '#', '.'],
+    ])?;
+    let pattern = Array2D::from_rows(&[vec![Some('#'), None], vec![None, Some('#')]])?;
+    assert_eq!(
+        board
+            .find_pattern_with_wildcards(&pattern)
+            .collect::<Vec<_>>(),
+        vec![(0, 0), (0, 1), (1, 0)]
+    );
+    let anything = Array2D::filled_with(2, 3, None);
+    assert_eq!(
+        board
+            .find_pattern_with_wildcards(&anything)
+            .collect::<Vec<_>>(),
+        vec![(0, 0), (1, 0)]
+    );
+
+    let array = pseudo_random_array(17, 13, 3);
+    let pattern = Array2D::from_rows(&[vec![Some(0), None, Some(1)], vec![None, Some(2), None]])?;
+    assert_eq!(
+        array
+            .find_pattern_with_wildcards(&pattern)
+            .collect::<Vec<_>>(),
+        find_pattern_naively(&array, &pattern)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_find_pattern_with_wildcards_matches_naive_search() -> Result<(), Error> {
+    let array = pseudo_random_array(19, 23, 2);
+    let masks = [
+        vec![vec![false, true, false], vec![true, true, true]],
+        vec![vec![true, false], vec![false, false], vec![false, true]],
+        vec![
+            vec![false, false, true, true],
+            vec![true, false, true, false],
+        ],
+    ];
+    for mask in &masks {
+        for &(row, column) in &[(0, 0), (5, 9), (19 - mask.len(), 23 - mask[0].len())] {
+            let rows = mask
+                .iter()
+                .enumerate()
+                .map(|(r, mask_row)| {
+                    mask_row
+                        .iter()
+                        .enumerate()
+                        .map(|(c, &keep)| Some(array[(row + r, column + c)]).filter(|_| keep))
+                        .collect()
+                })
+                .collect::<Vec<_>>();
+            let pattern = Array2D::from_rows(&rows)?;
+            let expected = find_pattern_naively(&array, &pattern);
+            assert!(expected.contains(&(row, column)));
+            assert_eq!(
+                array
+                    .find_pattern_with_wildcards(&pattern)
+                    .collect::<Vec<_>>(),
+                expected
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_find_pattern_oriented() -> Result<(), Error> {
+    let l_piece = Array2D::from_rows(&[vec![1, 0], vec![1, 0], vec![1, 1]])?;
+    let mut board = Array2D::filled_with(8, 8, 0);
+    let placements = [
+        ((0, 0), Orientation::Identity),
+        ((0, 4), Orientation::Rotate90),
+        ((4, 0), Orientation::FlipVertical),
+        ((5, 5), Orientation::AntiTranspose),
+    ];
+    for &((row, column), orientation) in &placements {
+        for ((piece_row, piece_column), &element) in
+            l_piece.oriented(orientation).enumerate_row_major()
+        {
+            board[(row + piece_row, column + piece_column)] = element;
+        }
+    }
+    let matches = board.find_pattern_oriented(&l_piece, &Orientation::ALL);
+    assert_eq!(matches.collect::<Vec<_>>(), placements.to_vec());
+
+    let rotations = [Orientation::Identity, Orientation::Rotate90];
+    assert_eq!(
+        board
+            .find_pattern_oriented(&l_piece, &rotations)
+            .collect::<Vec<_>>(),
+        placements[..2].to_vec()
+    );
+    assert_eq!(board.find_pattern_oriented(&l_piece, &[]).next(), None);
+
+    let square = Array2D::filled_with(2, 2, 1);
+    let block = Array2D::filled_with(2, 2, 1);
+    assert_eq!(
+        block
+            .find_pattern_oriented(&square, &Orientation::ALL)
+            .collect::<Vec<_>>(),
+        vec![((0, 0), Orientation::Identity)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_find_pattern_oriented_with_wildcards() -> Result<(), Error> {
+    let board = Array2D::from_rows(&[
+        vec!['#', '.', '#'],
+        vec!['#', '#', '.'],
+        vec!['#', '.', '#'],
+    ])?;
+    let corner = Array2D::from_rows(&[vec![Some('#'), Some('#')], vec![Some('#'), None]])?;
+    let matches = board
+        .find_pattern_oriented_with_wildcards(&corner, &Orientation::ALL)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        matches,
+        vec![
+            ((0, 0), Orientation::Rotate270),
+            ((1, 0), Orientation::Identity),
+        ]
+    );
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Coordinates and Directions //////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_coord_conversions() {
+    let coord = Coord::new(2, 3);
+    assert_eq!(coord.row, 2);
+    assert_eq!(coord.column, 3);
+    assert_eq!(Coord::from((2, 3)), coord);
+    assert_eq!(<(usize, usize)>::from(coord), (2, 3));
+    assert_eq!(Coord::default(), Coord::new(0, 0));
+    assert!(Coord::new(0, 5) < Coord::new(1, 0));
+}
+
+#[test]
+fn test_coord_arithmetic() {
+    let coord = Coord::new(2, 3);
+    assert_eq!(coord + (1, -3), Coord::new(3, 0));
+    assert_eq!(coord - (2, -1), Coord::new(0, 4));
+    assert_eq!(coord + Direction::NorthWest, Coord::new(1, 2));
+    assert_eq!(coord - Direction::NorthWest, Coord::new(3, 4));
+    assert_eq!(coord.checked_add((-2, -3)), Some(Coord::new(0, 0)));
+    assert_eq!(coord.checked_add((-3, 0)), None);
+    assert_eq!(coord.checked_add((0, -4)), None);
+    assert_eq!(Coord::new(usize::MAX, 0).checked_add((1, 0)), None);
+    for &direction in &Direction::ALL {
+        assert_eq!(coord + direction - direction, coord);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_coord_add_underflow() {
+    let _ = Coord::new(0, 3) + Direction::North;
+}
+
+#[test]
+#[should_panic]
+fn test_coord_sub_underflow() {
+    let _ = Coord::new(3, 0) - (0, 1);
+}
+
+#[test]
+fn test_direction() {
+    assert_eq!(Direction::North.rotate_cw(), Direction::East);
+    assert_eq!(Direction::NorthWest.rotate_cw(), Direction::NorthEast);
+    assert_eq!(Direction::North.rotate_ccw(), Direction::West);
+    assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+    for &direction in &Direction::ALL {
+        let (row, column) = direction.delta();
+        assert_eq!(direction.opposite().delta(), (-row, -column));
+        assert_eq!(direction.rotate_cw().delta(), (column, -row));
+        assert_eq!(direction.rotate_cw().rotate_ccw(), direction);
+        assert_eq!(direction.rotate_cw().rotate_cw(), direction.opposite());
+        assert_eq!(direction.is_diagonal(), row != 0 && column != 0);
+    }
+    assert_eq!(
+        Direction::CARDINAL
+            .iter()
+            .map(|direction| direction.delta())
+            .collect::<Vec<_>>(),
+        vec![(-1, 0), (0, 1), (1, 0), (0, -1)]
+    );
+}
+
+#[test]
+fn test_coord_indexing() -> Result<(), Error> {
+    let mut array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!(array[Coord::new(1, 2)], 6);
+    array[Coord::new(0, 1)] = 20;
+    assert_eq!(array[(0, 1)], 20);
+
+    assert_eq!(array.get(Coord::new(1, 0)), Some(&4));
+    assert_eq!(array.get(Coord::new(2, 0)), None);
+    *array.get_mut(Coord::new(1, 0)).unwrap() = 40;
+    assert_eq!(array.get_mut(Coord::new(0, 3)), None);
+    array.set(Coord::new(1, 1), 50)?;
+    assert_eq!(
+        array.set(Coord::new(5, 1), 0),
+        Err(Error::IndicesOutOfBounds {
+            row: 5,
+            column: 1,
+            num_rows: 2,
+            num_columns: 3
+        })
+    );
+    assert_eq!(array.as_rows(), vec![vec![1, 20, 3], vec![40, 50, 6]]);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_coord_index_out_of_bounds() {
+    let array = Array2D::filled_with(2, 3, 0);
+    let _ = array[Coord::new(2, 0)];
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Wrapping ////////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_get_wrapping() -> Result<(), Error> {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let mut array = Array2D::from_rows(&rows)?;
+    assert_eq!(array.get_wrapping(0, 0), Some(&1));
+    assert_eq!(array.get_wrapping(-1, -1), Some(&6));
+    assert_eq!(array.get_wrapping(2, 3), Some(&1));
+    assert_eq!(array.get_wrapping(-5, 10), Some(&5));
+    assert_eq!(array.get_wrapping(isize::MIN, isize::MAX), Some(&2));
+    *array.get_mut_wrapping(3, -4).unwrap() = 60;
+    assert_eq!(array.as_rows(), vec![vec![1, 2, 3], vec![4, 5, 60]]);
+
+    let mut empty = Array2D::<i32>::filled_with(0, 3, 0);
+    assert_eq!(empty.get_wrapping(0, 0), None);
+    assert_eq!(empty.get_mut_wrapping(-1, 1), None);
+    Ok(())
+}
+
+#[test]
+fn test_wrapping_index() -> Result<(), Error> {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let mut array = Array2D::from_rows(&rows)?;
+    let wrapping = array.wrapping();
+    for row in -4..4 {
+        for column in -6..6 {
+            assert_eq!(
+                wrapping[(row, column)],
+                array[(row.rem_euclid(2) as usize, column.rem_euclid(3) as usize)]
+            );
+        }
+    }
+
+    let mut wrapping = array.wrapping_mut();
+    wrapping[(-2, -3)] = 10;
+    wrapping[(1, 4)] += 40;
+    assert_eq!(wrapping[(-1, 1)], 45);
+    assert_eq!(array.as_rows(), vec![vec![10, 2, 3], vec![4, 45, 6]]);
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_wrapping_index_empty() {
+    let array = Array2D::<i32>::filled_with(2, 0, 0);
+    let _ = Wrapping(&array)[(0, 0)];
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Layouts /////////////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_into_layout() -> Result<(), Error> {
+    for num_rows in 0..6 {
+        for num_columns in 0..6 {
+            let array = Array2D::from_iter_row_major(num_rows, num_columns, 0..)?;
+            let column_major = array.clone().into_layout(Layout::ColumnMajor);
+            assert_eq!(column_major.layout(), Layout::ColumnMajor);
+            assert_eq!(column_major.as_row_major(), array.as_row_major());
+            assert_eq!(column_major.as_column_major(), array.as_column_major());
+            let row_major = column_major.into_layout(Layout::RowMajor);
+            assert_eq!(row_major.layout(), Layout::RowMajor);
+            assert_eq!(row_major.as_row_major(), array.as_row_major());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_layout_equality_and_hash() -> Result<(), Error> {
+    let array = Array2D::from_rows(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    let column_major = array.clone().into_layout(Layout::ColumnMajor);
+    assert_eq!(array.layout(), Layout::RowMajor);
+    assert_eq!(array, column_major);
+    assert_eq!(hash_of(&array), hash_of(&column_major));
+    let transposed = Array2D::from_columns(&[vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_ne!(column_major, transposed);
+    Ok(())
+}
+
+#[test]
+fn test_column_major_layout_access() -> Result<(), Error> {
+    let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let mut array = Array2D::from_rows(&rows)?.into_layout(Layout::ColumnMajor);
+    assert_eq!(array[(1, 0)], 4);
+    assert_eq!(array.get_row_major(2), Some(&3));
+    assert_eq!(array.get_column_major(2), Some(&2));
+    assert_eq!(array.get_row_major(6), None);
+    // SAFETY: Both indices are in bounds.
+    unsafe {
+        assert_eq!(array.get_unchecked((0, 2)), &3);
+        assert_eq!(array.get_unchecked_row_major(4), &5);
+    }
+    assert_eq!(array.row_iter(1)?.collect::<Vec<_>>(), vec![&4, &5, &6]);
+    assert_eq!(array.column_iter(2)?.collect::<Vec<_>>(), vec![&3, &6]);
+    assert_eq!(array.as_rows(), rows);
+    assert_eq!(array.as_columns(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    assert_eq!(array.enumerate_row_major().rev().nth(1), Some(((1, 1), &5)));
+    assert_eq!(array.enumerate_column_major().nth(3), Some(((1, 1), &5)));
+    assert_eq!(array.diagonal(0)?.collect::<Vec<_>>(), vec![&1, &5]);
+    assert_eq!(array.anti_diagonal(0)?.collect::<Vec<_>>(), vec![&3, &5]);
+    assert_eq!(array.anti_diagonal(1)?.collect::<Vec<_>>(), vec![&2, &4]);
+    assert_eq!(
+        array.slice((.., 1..))?.to_owned().as_rows(),
+        vec![vec![2, 3], vec![5, 6]]
+    );
+    assert_eq!(array.map_rows(|row| row.sum::<i32>()), vec![6, 15]);
+    assert_eq!(
+        array.positions(|&x| x % 2 == 0).collect::<Vec<_>>(),
+        vec![(0, 1), (1, 0), (1, 2)]
+    );
+
+    array.swap((0, 0), (1, 2))?;
+    array.set_row_major(1, 20)?;
+    for element in array.anti_diagonal_mut(0)? {
+        *element *= 10;
+    }
+    for element in array.row_iter_mut(1)? {
+        *element += 1;
+    }
+    let [first, last] = array.get_many_mut([(0, 0), (1, 2)])?;
+    std::mem::swap(first, last);
+    assert_eq!(array.as_rows(), vec![vec![2, 20, 30], vec![5, 51, 6]]);
+    Ok(())
+}
+
+#[test]
+fn test_column_major_layout_map_order() -> Result<(), Error> {
+    let mut array = Array2D::from_iter_row_major(3, 4, 0..)?.into_layout(Layout::ColumnMajor);
+    let mut visited = Vec::new();
+    array.map_in_place(|x| visited.push(*x));
+    assert_eq!(visited, (0..12).collect::<Vec<_>>());
+    let mut visited = Vec::new();
+    let mapped = array.into_map(|x| {
+        visited.push(x);
+        x * 2
+    });
+    assert_eq!(visited, (0..12).collect::<Vec<_>>());
+    assert_eq!(
+        mapped.as_row_major(),
+        (0..24).step_by(2).collect::<Vec<_>>()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_column_major_layout_zip() -> Result<(), Error> {
+    let row_major = Array2D::from_iter_row_major(3, 4, 0..)?;
+    let mut column_major = row_major.clone().into_layout(Layout::ColumnMajor);
+    let sums = row_major.zip_with(&column_major, |a, b| a + b)?;
+    assert_eq!(sums, row_major.map_row_major(|x| x * 2));
+    column_major.zip_apply(&row_major, |a, b| *a -= b)?;
+    assert_eq!(column_major, Array2D::filled_with(3, 4, 0));
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Storage Backends ////////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_from_slice() -> Result<(), Error> {
+    let buffer = (0..12).collect::<Vec<_>>();
+    let borrowed = Array2D::from_slice(3, 4, &buffer)?;
+    let owned = Array2D::from_row_major(3, 4, &buffer)?;
+    assert_eq!(borrowed, owned);
+    assert_eq!(owned, borrowed);
+    assert_eq!(borrowed.as_columns(), owned.as_columns());
+    assert_eq!(borrowed.get((2, 3)), Some(&11));
+    assert_eq!(borrowed.column_iter(1)?.sum::<i32>(), 1 + 5 + 9);
+    assert_eq!(hash_of(&borrowed), hash_of(&owned));
+    assert_eq!(
+        Array2D::from_slice(5, 2, &buffer),
+        Err(Error::DimensionMismatch {
+            expected: 10,
+            actual: 12
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn test_from_slice_read_apis() -> Result<(), Error> {
+    let buffer = (0..12).collect::<Vec<_>>();
+    let borrowed = Array2D::from_slice(3, 4, &buffer)?;
+    let owned = Array2D::from_row_major(3, 4, &buffer)?;
+    assert!(borrowed
+        .rows_iter()
+        .map(|row| row.collect::<Vec<_>>())
+        .eq(owned.rows_iter().map(|row| row.collect::<Vec<_>>())));
+    assert_eq!(
+        borrowed.neighbors8((1, 1))?.collect::<Vec<_>>(),
+        owned.neighbors8((1, 1))?.collect::<Vec<_>>()
+    );
+    assert_eq!(
+        borrowed.border_iter().collect::<Vec<_>>(),
+        owned.border_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        borrowed.spiral_iter().collect::<Vec<_>>(),
+        owned.spiral_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        borrowed.enumerate(Traversal::Hilbert).collect::<Vec<_>>(),
+        owned.enumerate(Traversal::Hilbert).collect::<Vec<_>>()
+    );
+    assert_eq!(borrowed.view().to_owned(), owned);
+    assert_eq!(
+        borrowed.map_row_major(|x| x * 2),
+        owned.map_row_major(|x| x * 2)
+    );
+    assert_eq!(borrowed.into_map(|x| x + 1), owned.into_map(|x| x + 1));
+    Ok(())
+}
+
+#[test]
+fn test_from_slice_patterns_and_zip() -> Result<(), Error> {
+    let board = [0, 1, 0, 1, 1, 1, 0, 1, 0];
+    let board = Array2D::from_slice(3, 3, &board)?;
+    let pattern = [1, 1];
+    let pattern = Array2D::from_slice(1, 2, &pattern)?;
+    assert_eq!(
+        board.find_pattern(&pattern).collect::<Vec<_>>(),
+        vec![(1, 0), (1, 1)]
+    );
+    let owned = Array2D::filled_with(3, 3, 1);
+    let sums = owned.zip_with(&board, |a, b| a + b)?;
+    assert_eq!(sums.as_row_major(), vec![1, 2, 1, 2, 2, 2, 1, 2, 1]);
+    assert_eq!(Wrapping(&board)[(-1, -1)], 0);
+    Ok(())
+}
+
+#[test]
+fn test_from_mut_slice() -> Result<(), Error> {
+    let mut frame = vec![0u8; 6];
+    {
+        let mut array = Array2D::from_mut_slice(2, 3, &mut frame)?;
+        array[(0, 1)] = 1;
+        array.set((1, 2), 2)?;
+        for element in array.column_iter_mut(0)? {
+            *element = 9;
+        }
+        array.swap((0, 1), (1, 1))?;
+    }
+    assert_eq!(frame, vec![9, 0, 0, 9, 1, 2]);
+    Ok(())
+}
+
+#[test]
+fn test_from_storage() -> Result<(), Error> {
+    let boxed: Box<[f32]> = vec![1.0, 2.0, 3.0, 4.0].into_boxed_slice();
+    let mut array = Array2D::from_storage(2, 2, boxed)?;
+    array.map_in_place(|x| *x *= 2.0);
+    let array = array.into_layout(Layout::ColumnMajor);
+    assert_eq!(array.as_rows(), vec![vec![2.0, 4.0], vec![6.0, 8.0]]);
+    assert_eq!(&*array.into_storage(), &[2.0, 6.0, 4.0, 8.0]);
+
+    let shared: Arc<[i32]> = Arc::from(vec![1, 2, 3, 4, 5, 6]);
+    let array = Array2D::from_storage(3, 2, Arc::clone(&shared))?;
+    let copy = array.clone();
+    assert_eq!(array.as_columns(), vec![vec![1, 3, 5], vec![2, 4, 6]]);
+    assert_eq!(copy, array);
+    assert_eq!(Arc::strong_count(&shared), 3);
+
+    let shared: Rc<[i32]> = Rc::from(vec![1, 2]);
+    assert!(Array2D::from_storage(2, 2, shared).is_err());
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Serialization and Reflection ////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "serde")]
+mod serialization {
+    use array2d::{Array2D, Error, Layout};
+
+    #[test]
+    fn test_serde_round_trip() -> Result<(), Error> {
+        let array = Array2D::from_iter_row_major(2, 3, 0..)?.into_layout(Layout::ColumnMajor);
+        let json = serde_json::to_string(&array).unwrap();
+        let deserialized = serde_json::from_str::<Array2D<i32>>(&json).unwrap();
+        assert_eq!(deserialized, array);
+        assert_eq!(deserialized.layout(), Layout::ColumnMajor);
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_without_layout() {
+        let json = r#"{"array":[1,2,3,4],"num_rows":2,"num_columns":2}"#;
+        let array = serde_json::from_str::<Array2D<i32>>(json).unwrap();
+        assert_eq!(array.as_rows(), vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(array.layout(), Layout::RowMajor);
+    }
+
+    #[test]
+    fn test_deserialize_dimension_mismatch() {
+        let json = r#"{"array":[1],"num_rows":100000,"num_columns":100000}"#;
+        let error = serde_json::from_str::<Array2D<u64>>(json).unwrap_err();
+        assert!(error.to_string().contains("dimension mismatch"));
+        let json = r#"{"array":[1,2,3],"num_rows":2,"num_columns":2}"#;
+        assert!(serde_json::from_str::<Array2D<u64>>(json).is_err());
+    }
+}
+
+#[cfg(feature = "bevy_reflect")]
+mod reflection {
+    use array2d::{Array2D, Error};
+    use bevy_reflect::{Reflect, ReflectRef};
+
+    #[test]
+    fn test_reflect_as_value() -> Result<(), Error> {
+        let mut array = Array2D::from_rows(&[vec![1, 2], vec![3, 4]])?;
+        assert!(matches!(array.reflect_ref(), ReflectRef::Value(_)));
+        let other = Array2D::filled_with(1, 3, 7);
+        array.apply(&other);
+        assert_eq!(array, other);
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Parallel Iteration //////////////////////////////////////////////////////////
+////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use array2d::{Array2D, Error, Layout};
+    use rayon::prelude::*;
+
+    #[test]
+    fn test_par_from_fn() -> Result<(), Error> {
+        let array = Array2D::par_from_fn(3, 4, |(row, column)| (row, column));
+        let expected = Array2D::from_iter_row_major(3, 4, array.indices_row_major())?;
+        assert_eq!(array, expected);
+        assert_eq!(Array2D::par_from_fn(0, 4, |_| 0).num_rows(), 0);
+        assert_eq!(Array2D::par_from_fn(4, 0, |_| 0).num_rows(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_rows_iter() -> Result<(), Error> {
+        let mut array = Array2D::from_iter_row_major(100, 7, 0..)?;
+        let rows = array
+            .par_rows_iter()
+            .map(|row| row.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, array.as_rows());
+        array
+            .par_rows_iter_mut()
+            .enumerate()
+            .for_each(|(row, elements)| elements.for_each(|element| *element = row));
+        let expected = Array2D::filled_by_row_major(100, 7, {
+            let mut counter = 0;
+            move || {
+                counter += 1;
+                (counter - 1) / 7
+            }
+        });
+        assert_eq!(array, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_rows_iter_empty_rows() {
+        let mut array: Array2D<i32> = Array2D::filled_with(3, 0, 0);
+        assert_eq!(array.par_rows_iter().len(), 3);
+        assert!(array.par_rows_iter().all(|row| row.len() == 0));
+        assert_eq!(array.par_rows_iter_mut().len(), 3);
+    }
+
+    #[test]
+    fn test_par_elements_iter() -> Result<(), Error> {
+        let mut array = Array2D::from_iter_row_major(50, 30, 0..)?;
+        let elements = array.par_elements_iter().copied().collect::<Vec<_>>();
+        assert_eq!(elements, array.as_row_major());
+        array
+            .par_elements_iter_mut()
+            .for_each(|element| *element *= 2);
+        assert_eq!(
+            array,
+            Array2D::from_iter_row_major(50, 30, (0..).step_by(2))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_enumerate() -> Result<(), Error> {
+        let array = Array2D::from_iter_row_major(20, 13, 0..)?;
+        let parallel = array.par_enumerate().collect::<Vec<_>>();
+        let sequential = array.enumerate_row_major().collect::<Vec<_>>();
+        assert_eq!(parallel, sequential);
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_map() -> Result<(), Error> {
+        let array = Array2D::from_iter_row_major(40, 25, 0..)?;
+        assert_eq!(array.par_map(|x| x * 3), array.map_row_major(|x| x * 3));
+        Ok(())
+    }
+    #[test]
+    fn test_par_rows_are_not_split() -> Result<(), Error> {
+        let array = Array2D::from_iter_row_major(64, 3, 0..)?;
+        let threads = array
+            .par_enumerate()
+            .map(|((row, _), _)| (row, rayon::current_thread_index()))
+            .collect::<Vec<_>>();
+        for row in threads.chunks(3) {
+            assert!(row.iter().all(|&thread| thread == row[0]));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_column_major_layout() -> Result<(), Error> {
+        let row_major = Array2D::from_iter_row_major(20, 13, 0..)?;
+        let mut array = row_major.clone().into_layout(Layout::ColumnMajor);
+        let elements = array.par_elements_iter().copied().collect::<Vec<_>>();
+        assert_eq!(elements, row_major.as_row_major());
+        let rows = array
+            .par_rows_iter()
+            .map(|row| row.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, row_major.as_rows());
+        array
+            .par_elements_iter_mut()
+            .for_each(|element| *element *= 2);
+        assert_eq!(array, row_major.map_row_major(|x| x * 2));
+        Ok(())
+    }
+}